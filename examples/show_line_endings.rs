@@ -0,0 +1,63 @@
+//! Prints a file with its line endings made visible, `cat -A` style.
+//!
+//! ```sh
+//! cargo run --example show_line_endings -- <path> [control|escaped|caret|return] [--annotate]
+//! ```
+
+use line_ending::{LineEnding, Markers, VisibleLineEndings};
+use std::{env, fs, process};
+
+/// Usage message printed for invalid arguments.
+const USAGE: &str = "usage: show_line_endings <path> [control|escaped|caret|return] [--annotate]";
+
+fn main() {
+    let mut path = None;
+    let mut markers = Markers::CONTROL_PICTURES;
+    let mut annotate = false;
+
+    // The path, marker style, and flags may be given in any order
+    for arg in env::args().skip(1) {
+        match arg.as_str() {
+            "--annotate" => annotate = true,
+            "control" => markers = Markers::CONTROL_PICTURES,
+            "escaped" => markers = Markers::ESCAPED,
+            "caret" => markers = Markers::CARET,
+            "return" => markers = Markers::RETURN_SYMBOL,
+            _ if arg.starts_with("--") || path.is_some() => {
+                eprintln!("unexpected argument: {}\n{}", arg, USAGE);
+                process::exit(2);
+            }
+            _ => path = Some(arg),
+        }
+    }
+
+    let Some(path) = path else {
+        eprintln!("{}", USAGE);
+        process::exit(2);
+    };
+
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("failed to read {}: {}", path, e);
+        process::exit(1);
+    });
+
+    // Each line ending is already rendered as its marker followed by a newline
+    print!(
+        "{}",
+        VisibleLineEndings::new(&text)
+            .markers(markers)
+            .annotate(annotate)
+    );
+    if !text.is_empty() && !text.ends_with(['\r', '\n']) {
+        println!();
+    }
+
+    let scores = LineEnding::score_mixed_types(&text);
+    eprintln!(
+        "detected: {} (CRLF: {}, LF: {}, CR: {})",
+        LineEnding::from(text.as_str()),
+        scores[&LineEnding::CRLF],
+        scores[&LineEnding::LF],
+        scores[&LineEnding::CR]
+    );
+}
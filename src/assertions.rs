@@ -0,0 +1,272 @@
+//! Support code for [`assert_eq_line_endings!`](crate::assert_eq_line_endings)
+//! and [`assert_eq_ignoring_line_endings!`](crate::assert_eq_ignoring_line_endings).
+
+use crate::line_ending::split_first_line;
+use crate::{LineEnding, Markers};
+use std::fmt::Write;
+
+/// Asserts that two strings are equal, including their line endings.
+///
+/// On failure, the differing lines are printed with their line endings made
+/// visible (`␍␊` for CRLF, `␊` for LF, `␍` for CR), so strings that differ only
+/// in `\r` produce a readable message.
+///
+/// Accepts anything implementing `AsRef<str>`, and an optional custom message
+/// like [`assert_eq!`].
+///
+/// # Example
+///
+/// ```
+/// use line_ending::assert_eq_line_endings;
+///
+/// assert_eq_line_endings!("first\r\nsecond", String::from("first\r\nsecond"));
+/// ```
+///
+/// ```should_panic
+/// use line_ending::assert_eq_line_endings;
+///
+/// // Panics with:
+/// // line 1:
+/// //    left: first␍␊
+/// //   right: first␊
+/// assert_eq_line_endings!("first\r\nsecond", "first\nsecond");
+/// ```
+#[macro_export]
+macro_rules! assert_eq_line_endings {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_eq_line_endings!(@impl $left, $right, false, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::assert_eq_line_endings!(
+            @impl $left, $right, false, ::core::option::Option::Some(::std::format!($($arg)+))
+        )
+    };
+    (@impl $left:expr, $right:expr, $ignore_line_endings:expr, $message:expr) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let left: &str = ::core::convert::AsRef::<str>::as_ref(left);
+                let right: &str = ::core::convert::AsRef::<str>::as_ref(right);
+                let equal = if $ignore_line_endings {
+                    $crate::LineEnding::eq_ignore_line_endings(left, right)
+                } else {
+                    left == right
+                };
+
+                if !equal {
+                    ::core::panic!(
+                        "{}",
+                        $crate::assertions::failure_message(
+                            left,
+                            right,
+                            $ignore_line_endings,
+                            $message
+                        )
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that two strings are equal after normalizing their line endings,
+/// i.e. LF, CRLF, and CR are all treated as equal.
+///
+/// On failure, the differing lines are printed with their line endings made
+/// visible, as with [`assert_eq_line_endings!`](crate::assert_eq_line_endings).
+///
+/// # Example
+///
+/// ```
+/// use line_ending::assert_eq_ignoring_line_endings;
+///
+/// assert_eq_ignoring_line_endings!("first\r\nsecond\r\n", "first\nsecond\n");
+/// ```
+#[macro_export]
+macro_rules! assert_eq_ignoring_line_endings {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_eq_line_endings!(@impl $left, $right, true, ::core::option::Option::None)
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        $crate::assert_eq_line_endings!(
+            @impl $left, $right, true, ::core::option::Option::Some(::std::format!($($arg)+))
+        )
+    };
+}
+
+/// Splits `s` into lines, each paired with its line ending (empty for the
+/// final line).
+fn lines_with_endings(s: &str) -> Vec<(&str, &str)> {
+    let mut lines = Vec::new();
+    let mut remaining = s;
+
+    while !remaining.is_empty() {
+        let (end, next_start) = split_first_line(remaining.as_bytes());
+        lines.push((&remaining[..end], &remaining[end..next_start]));
+        remaining = &remaining[next_start..];
+    }
+
+    lines
+}
+
+/// Renders a line with its line ending replaced by a visible marker.
+fn visualize(content: &str, line_ending: &str) -> String {
+    let marker = line_ending
+        .parse::<LineEnding>()
+        .map_or("", |le| Markers::CONTROL_PICTURES.for_line_ending(le));
+
+    format!("{}{}", content, marker)
+}
+
+/// Largest number of cells (`left lines × right lines`, after removing the
+/// common prefix and suffix) for which a full line diff is computed. Beyond
+/// this, the remaining lines are compared in order instead.
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// A reported difference between the left and right lines, by index.
+enum Difference {
+    /// A left line replaced by a right line.
+    Changed(usize, usize),
+    /// A left line missing from the right.
+    Removed(usize),
+    /// A right line missing from the left.
+    Added(usize),
+}
+
+/// Computes a line diff of `left` and `right` using the longest common
+/// subsequence of lines, returning only the differences.
+///
+/// Within each run of differing lines, removed and added lines are paired up
+/// in order and reported as changed lines.
+fn diff_lines<T, F>(left: &[T], right: &[T], eq: F) -> Vec<Difference>
+where
+    F: Fn(&T, &T) -> bool,
+{
+    let prefix = left.iter().zip(right).take_while(|(l, r)| eq(l, r)).count();
+    let suffix = left[prefix..]
+        .iter()
+        .rev()
+        .zip(right[prefix..].iter().rev())
+        .take_while(|(l, r)| eq(l, r))
+        .count();
+
+    let left_end = left.len() - suffix;
+    let right_end = right.len() - suffix;
+    let (n, m) = (left_end - prefix, right_end - prefix);
+
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+    let mut differences = Vec::new();
+
+    let mut flush = |removed: &mut Vec<usize>, added: &mut Vec<usize>| {
+        let paired = removed.len().min(added.len());
+        for k in 0..paired {
+            differences.push(Difference::Changed(removed[k], added[k]));
+        }
+        differences.extend(removed[paired..].iter().map(|&i| Difference::Removed(i)));
+        differences.extend(added[paired..].iter().map(|&j| Difference::Added(j)));
+        removed.clear();
+        added.clear();
+    };
+
+    if n.saturating_mul(m) > MAX_DIFF_CELLS {
+        removed.extend(prefix..left_end);
+        added.extend(prefix..right_end);
+        flush(&mut removed, &mut added);
+        return differences;
+    }
+
+    // lcs[i * (m + 1) + j] is the LCS length of the middle lines from i and j on
+    let mut lcs = vec![0u32; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i * (m + 1) + j] = if eq(&left[prefix + i], &right[prefix + j]) {
+                lcs[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                lcs[(i + 1) * (m + 1) + j].max(lcs[i * (m + 1) + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && eq(&left[prefix + i], &right[prefix + j]) {
+            flush(&mut removed, &mut added);
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && lcs[(i + 1) * (m + 1) + j] >= lcs[i * (m + 1) + j + 1]) {
+            removed.push(prefix + i);
+            i += 1;
+        } else {
+            added.push(prefix + j);
+            j += 1;
+        }
+    }
+    flush(&mut removed, &mut added);
+
+    differences
+}
+
+/// Builds the panic message for a failed line ending assertion.
+///
+/// Lines are matched up with a line diff, so an inserted or removed line is
+/// reported once rather than shifting every later line out of place.
+#[doc(hidden)]
+pub fn failure_message(
+    left: &str,
+    right: &str,
+    ignore_line_endings: bool,
+    message: Option<String>,
+) -> String {
+    let mut output = String::from("assertion `left == right` failed");
+    if ignore_line_endings {
+        output.push_str(" (ignoring line endings)");
+    }
+    if let Some(message) = message {
+        let _ = write!(output, ": {}", message);
+    }
+
+    let left_lines = lines_with_endings(left);
+    let right_lines = lines_with_endings(right);
+
+    let eq = |l: &(&str, &str), r: &(&str, &str)| {
+        if ignore_line_endings {
+            l.0 == r.0 && l.1.is_empty() == r.1.is_empty()
+        } else {
+            l == r
+        }
+    };
+    let render = |line: &(&str, &str)| visualize(line.0, line.1);
+
+    for difference in diff_lines(&left_lines, &right_lines, eq) {
+        let (header, left_line, right_line) = match difference {
+            Difference::Changed(i, j) if i == j => (
+                format!("line {}", i + 1),
+                render(&left_lines[i]),
+                render(&right_lines[j]),
+            ),
+            Difference::Changed(i, j) => (
+                format!("left line {}, right line {}", i + 1, j + 1),
+                render(&left_lines[i]),
+                render(&right_lines[j]),
+            ),
+            Difference::Removed(i) => (
+                format!("line {}", i + 1),
+                render(&left_lines[i]),
+                "<missing>".to_string(),
+            ),
+            Difference::Added(j) => (
+                format!("line {}", j + 1),
+                "<missing>".to_string(),
+                render(&right_lines[j]),
+            ),
+        };
+
+        let _ = write!(
+            output,
+            "\n{}:\n   left: {}\n  right: {}",
+            header, left_line, right_line
+        );
+    }
+
+    output
+}
@@ -0,0 +1,68 @@
+use crate::line_ending::{split_first_line, split_last_line};
+
+/// A trait to extend byte slices with line ending operations, for text that
+/// is not (or not known to be) valid UTF-8.
+pub trait BytesLineEndingExt {
+    /// Returns an iterator over the lines of the byte slice, splitting on any
+    /// line ending (LF, CRLF, or CR), even when they are mixed.
+    ///
+    /// Behaves like [`StrLineEndingExt::lines_any_ending`](crate::StrLineEndingExt::lines_any_ending),
+    /// including iterating in reverse with `.rev()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::BytesLineEndingExt;
+    ///
+    /// let text = b"first\r\nsecond\rthird\n";
+    ///
+    /// let lines: Vec<&[u8]> = text.lines_any_ending().collect();
+    /// assert_eq!(lines, vec![&b"first"[..], b"second", b"third"]);
+    ///
+    /// let last: Vec<&[u8]> = text.lines_any_ending().rev().take(1).collect();
+    /// assert_eq!(last, vec![&b"third"[..]]);
+    /// ```
+    fn lines_any_ending(&self) -> ByteLinesAnyEnding<'_>;
+}
+
+impl BytesLineEndingExt for [u8] {
+    fn lines_any_ending(&self) -> ByteLinesAnyEnding<'_> {
+        ByteLinesAnyEnding { remaining: self }
+    }
+}
+
+/// An iterator over the lines of a byte slice, split on any line ending.
+///
+/// Created by [`BytesLineEndingExt::lines_any_ending`].
+#[derive(Debug, Clone)]
+pub struct ByteLinesAnyEnding<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> Iterator for ByteLinesAnyEnding<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let (end, next_start) = split_first_line(self.remaining);
+        let line = &self.remaining[..end];
+        self.remaining = &self.remaining[next_start..];
+        Some(line)
+    }
+}
+
+impl DoubleEndedIterator for ByteLinesAnyEnding<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let (start, end) = split_last_line(self.remaining);
+        let line = &self.remaining[start..end];
+        self.remaining = &self.remaining[..start];
+        Some(line)
+    }
+}
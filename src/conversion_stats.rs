@@ -0,0 +1,109 @@
+use crate::line_ending::score_bytes;
+use crate::{LineEnding, LineEndingScores};
+
+/// Summary of the line endings rewritten by a conversion.
+///
+/// Returned by [`LineEnding::apply_with_stats`] and
+/// [`LineEnding::apply_in_place_with_stats`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConversionStats {
+    /// The target line ending of the conversion.
+    pub target: LineEnding,
+    /// Number of line endings of each original type that were rewritten to the
+    /// target. The target's own entry is always `0`.
+    pub rewritten: LineEndingScores,
+    /// Number of line endings that already matched the target.
+    pub unchanged: usize,
+    /// Whether the converted text differs from the input.
+    pub changed: bool,
+}
+
+impl ConversionStats {
+    /// Builds the statistics for converting `bytes` to `target`.
+    pub(crate) fn new(bytes: &[u8], target: LineEnding) -> Self {
+        let mut rewritten = score_bytes(bytes);
+        let unchanged = rewritten.insert(target, 0).unwrap_or(0);
+        let changed = rewritten.values().any(|&count| count > 0);
+
+        Self {
+            target,
+            rewritten,
+            unchanged,
+            changed,
+        }
+    }
+
+    /// Returns the total number of line endings that were rewritten.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let (_, stats) = LineEnding::LF.apply_with_stats("a\r\nb\rc\n");
+    /// assert_eq!(stats.total_rewritten(), 2);
+    /// ```
+    pub fn total_rewritten(&self) -> usize {
+        self.rewritten.values().sum()
+    }
+
+    /// Returns the total number of line endings in the input.
+    pub fn total(&self) -> usize {
+        self.total_rewritten() + self.unchanged
+    }
+}
+
+impl LineEnding {
+    /// Applies a specific line ending type to an existing string, returning the
+    /// converted text together with a summary of what was rewritten.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let (text, stats) = LineEnding::CRLF.apply_with_stats("first\nsecond\r\nthird\r");
+    ///
+    /// assert_eq!(text, "first\r\nsecond\r\nthird\r\n");
+    /// assert_eq!(stats.rewritten[&LineEnding::LF], 1);
+    /// assert_eq!(stats.rewritten[&LineEnding::CR], 1);
+    /// assert_eq!(stats.unchanged, 1);
+    /// assert!(stats.changed);
+    /// ```
+    pub fn apply_with_stats(&self, s: &str) -> (String, ConversionStats) {
+        let stats = ConversionStats::new(s.as_bytes(), *self);
+        let converted = if stats.changed {
+            self.apply(s)
+        } else {
+            s.to_string()
+        };
+
+        (converted, stats)
+    }
+
+    /// Applies this line ending type to a `String` in place, returning a summary
+    /// of what was rewritten.
+    ///
+    /// See [`LineEnding::apply_in_place`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let mut text = String::from("first\nsecond\n");
+    /// let stats = LineEnding::LF.apply_in_place_with_stats(&mut text);
+    ///
+    /// assert_eq!(stats.unchanged, 2);
+    /// assert!(!stats.changed);
+    /// ```
+    pub fn apply_in_place_with_stats(&self, s: &mut String) -> ConversionStats {
+        let stats = ConversionStats::new(s.as_bytes(), *self);
+        if stats.changed {
+            self.apply_in_place(s);
+        }
+
+        stats
+    }
+}
@@ -0,0 +1,224 @@
+use crate::line_ending::predominant;
+use crate::{LineEnding, LineEndingScores};
+use std::ops::Range;
+
+/// An iterator over the records of CSV (or similar delimited) text, splitting
+/// on line endings outside quoted fields.
+///
+/// Line endings inside quoted fields are part of the field's content, so a
+/// quoted field containing an embedded CRLF does not end the record. As in
+/// [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180), a quote (`"` by default)
+/// only opens a quoted field at the start of a field, i.e. at the start of a
+/// record or right after a delimiter (`,` by default); elsewhere it is an
+/// ordinary character. A doubled quote (`""`) inside a quoted field is an
+/// escaped quote. Any of LF, CRLF, and CR outside quoted fields terminates a
+/// record, and, like
+/// [`StrLineEndingExt::lines_any_ending`](crate::StrLineEndingExt::lines_any_ending),
+/// a trailing terminator does not produce a final empty record.
+///
+/// A quote left open at the end of the text extends its record to the end.
+///
+/// # Example
+///
+/// ```
+/// use line_ending::{CsvRecords, LineEnding};
+///
+/// let csv = "id,note\r\n1,\"first\r\nsecond\"\r\n2,\"say \"\"hi\"\"\"\r\n";
+/// let records: Vec<&str> = CsvRecords::new(csv).map(|record| record.as_str()).collect();
+///
+/// assert_eq!(records, vec!["id,note", "1,\"first\r\nsecond\"", "2,\"say \"\"hi\"\"\""]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CsvRecords<'a> {
+    text: &'a str,
+    position: usize,
+    quote: char,
+    delimiter: char,
+}
+
+impl<'a> CsvRecords<'a> {
+    /// Creates an iterator over the records of `text`, using `"` as the quote
+    /// character and `,` as the field delimiter.
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            position: 0,
+            quote: '"',
+            delimiter: ',',
+        }
+    }
+
+    /// Sets the quote character (`"` by default).
+    pub fn quote(mut self, quote: char) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// Sets the field delimiter (`,` by default), after which a quote opens a
+    /// quoted field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::CsvRecords;
+    ///
+    /// let tsv = "a\t\"x\ny\"\nb\tc";
+    /// assert_eq!(CsvRecords::new(tsv).delimiter('\t').count(), 2);
+    /// ```
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Detects the record terminator of the remaining text, counting only line
+    /// endings outside quoted fields.
+    ///
+    /// Ties, and text with a single record, resolve to `CRLF`, the terminator
+    /// RFC 4180 specifies.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{CsvRecords, LineEnding};
+    ///
+    /// // The embedded CRLFs would make `LineEnding::from` pick CRLF
+    /// let csv = "a,\"x\r\ny\r\nz\"\nb,c\n";
+    /// assert_eq!(LineEnding::from(csv), LineEnding::CRLF);
+    /// assert_eq!(CsvRecords::new(csv).record_line_ending(), LineEnding::LF);
+    /// ```
+    pub fn record_line_ending(&self) -> LineEnding {
+        let mut scores: LineEndingScores = LineEnding::ALL.iter().map(|&le| (le, 0)).collect();
+
+        for line_ending in self.clone().filter_map(|record| record.line_ending()) {
+            *scores.entry(line_ending).or_insert(0) += 1;
+        }
+
+        predominant(&scores)
+    }
+
+    /// Consumes the remaining records, rewriting every record terminator as
+    /// `record_line_ending`.
+    ///
+    /// Line breaks inside quoted fields are converted to `field_line_ending`,
+    /// or left untouched if it is `None`. A final record without a terminator
+    /// is left without one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{CsvRecords, LineEnding};
+    ///
+    /// let csv = "a,\"x\r\ny\"\nb,c\n";
+    ///
+    /// assert_eq!(
+    ///     CsvRecords::new(csv).apply(LineEnding::CRLF, None),
+    ///     "a,\"x\r\ny\"\r\nb,c\r\n"
+    /// );
+    /// assert_eq!(
+    ///     CsvRecords::new(csv).apply(LineEnding::CRLF, Some(LineEnding::LF)),
+    ///     "a,\"x\ny\"\r\nb,c\r\n"
+    /// );
+    /// ```
+    pub fn apply(
+        self,
+        record_line_ending: LineEnding,
+        field_line_ending: Option<LineEnding>,
+    ) -> String {
+        let mut result = String::with_capacity(self.text.len() - self.position);
+
+        for record in self {
+            match field_line_ending {
+                Some(field_line_ending) => result.push_str(&field_line_ending.apply(record.text)),
+                None => result.push_str(record.text),
+            }
+            if record.line_ending.is_some() {
+                result.push_str(record_line_ending.as_str());
+            }
+        }
+
+        result
+    }
+}
+
+impl<'a> Iterator for CsvRecords<'a> {
+    type Item = CsvRecord<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.text[self.position..];
+        if rest.is_empty() {
+            return None;
+        }
+
+        let start = self.position;
+        let mut in_quotes = false;
+        let mut at_field_start = true;
+        let mut chars = rest.char_indices().peekable();
+
+        let (end, line_ending) = loop {
+            let Some((i, ch)) = chars.next() else {
+                break (rest.len(), None);
+            };
+
+            if in_quotes {
+                // A doubled quote is an escaped quote; a single one closes the field
+                if ch == self.quote && chars.next_if(|&(_, ch)| ch == self.quote).is_none() {
+                    in_quotes = false;
+                }
+                continue;
+            }
+
+            match ch {
+                _ if ch == self.quote && at_field_start => in_quotes = true,
+                _ if ch == self.delimiter => {
+                    at_field_start = true;
+                    continue;
+                }
+                '\r' => {
+                    if chars.next_if(|&(_, ch)| ch == '\n').is_some() {
+                        break (i, Some(LineEnding::CRLF));
+                    }
+                    break (i, Some(LineEnding::CR));
+                }
+                '\n' => break (i, Some(LineEnding::LF)),
+                _ => {}
+            }
+            at_field_start = false;
+        };
+
+        let terminator_len = line_ending.map_or(0, |le| le.as_str().len());
+        self.position = start + end + terminator_len;
+
+        Some(CsvRecord {
+            text: &rest[..end],
+            start,
+            line_ending,
+        })
+    }
+}
+
+/// A record yielded by [`CsvRecords`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvRecord<'a> {
+    text: &'a str,
+    start: usize,
+    line_ending: Option<LineEnding>,
+}
+
+impl<'a> CsvRecord<'a> {
+    /// Returns the text of the record, including any line breaks inside quoted
+    /// fields but excluding the record terminator.
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    /// Returns the record terminator, or `None` if the record runs to the end
+    /// of the text.
+    pub fn line_ending(&self) -> Option<LineEnding> {
+        self.line_ending
+    }
+
+    /// Returns the byte range of [`CsvRecord::as_str`] in the source.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+}
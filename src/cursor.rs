@@ -0,0 +1,146 @@
+use crate::LineEnding;
+use std::iter::Peekable;
+
+/// A position in a character stream, as tracked by [`LineTrackingCursor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Position {
+    /// Zero-based byte offset (in UTF-8) from the start of the stream.
+    pub offset: usize,
+    /// Zero-based line number.
+    pub line: usize,
+    /// Zero-based column, in characters.
+    pub column: usize,
+}
+
+/// A range between two [`Position`]s, e.g. the extent of a token.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    /// Position of the first character of the span.
+    pub start: Position,
+    /// Position just past the last character of the span.
+    pub end: Position,
+}
+
+impl Span {
+    /// Returns the byte range of the span.
+    pub fn range(&self) -> std::ops::Range<usize> {
+        self.start.offset..self.end.offset
+    }
+}
+
+/// A character cursor for hand-written lexers that tracks byte offset, line,
+/// and column as characters are consumed.
+///
+/// LF, CRLF, and CR each count as a single line break, whether consumed with
+/// [`LineTrackingCursor::consume_line_ending`] or one character at a time with
+/// `next`. Only `peek` is used for lookahead, so the source iterator need not be
+/// `Clone`.
+///
+/// # Example
+///
+/// ```
+/// use line_ending::{LineEnding, LineTrackingCursor, Position};
+///
+/// let mut cursor = LineTrackingCursor::new("let x\r\n= 1".chars());
+///
+/// while cursor.peek().is_some_and(|ch| !ch.is_whitespace()) {
+///     cursor.next();
+/// }
+/// cursor.next(); // Skip the space
+///
+/// let start = cursor.position();
+/// cursor.next();
+/// let span = cursor.span_from(start);
+/// assert_eq!(span.range(), 4..5); // `x`
+///
+/// assert_eq!(cursor.consume_line_ending(), Some(LineEnding::CRLF));
+/// assert_eq!(cursor.position(), Position { offset: 7, line: 1, column: 0 });
+/// ```
+#[derive(Debug)]
+pub struct LineTrackingCursor<I: Iterator<Item = char>> {
+    iter: Peekable<I>,
+    position: Position,
+    /// Whether the last consumed character was a `\r`, so that a following
+    /// `\n` completes a CRLF rather than starting another line.
+    after_cr: bool,
+}
+
+impl<I: Iterator<Item = char>> LineTrackingCursor<I> {
+    /// Creates a cursor at the start of `iter`.
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter: iter.peekable(),
+            position: Position::default(),
+            after_cr: false,
+        }
+    }
+
+    /// Returns the position of the next character to be consumed.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the span from `start` (typically captured with
+    /// [`LineTrackingCursor::position`] at the start of a token) to the current
+    /// position.
+    pub fn span_from(&self, start: Position) -> Span {
+        Span {
+            start,
+            end: self.position,
+        }
+    }
+
+    /// Peeks at the next character without consuming it.
+    pub fn peek(&mut self) -> Option<&char> {
+        self.iter.peek()
+    }
+
+    /// Consumes the line ending at the front of the stream, if any, and returns
+    /// its type. See [`PeekableLineEndingExt::consume_line_ending`](crate::PeekableLineEndingExt::consume_line_ending).
+    pub fn consume_line_ending(&mut self) -> Option<LineEnding> {
+        match self.iter.peek() {
+            Some('\r') => {
+                self.next();
+                if self.iter.peek() == Some(&'\n') {
+                    self.next();
+                    Some(LineEnding::CRLF)
+                } else {
+                    Some(LineEnding::CR)
+                }
+            }
+            Some('\n') => {
+                self.next();
+                Some(LineEnding::LF)
+            }
+            _ => None,
+        }
+    }
+
+    fn advance(&mut self, ch: char) {
+        self.position.offset += ch.len_utf8();
+
+        match ch {
+            '\n' if self.after_cr => {
+                // Second half of a CRLF; the line break was counted at the `\r`
+            }
+            '\r' | '\n' => {
+                self.position.line += 1;
+                self.position.column = 0;
+            }
+            _ => self.position.column += 1,
+        }
+
+        self.after_cr = ch == '\r';
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for LineTrackingCursor<I> {
+    type Item = char;
+
+    /// Consumes the next character, updating the tracked position.
+    fn next(&mut self) -> Option<Self::Item> {
+        let ch = self.iter.next()?;
+        self.advance(ch);
+        Some(ch)
+    }
+}
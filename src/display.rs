@@ -0,0 +1,129 @@
+use crate::line_ending::find_line_ending;
+use crate::LineEnding;
+use std::fmt;
+
+/// A [`Display`](fmt::Display) wrapper that writes text with its line endings
+/// converted, without allocating an intermediate `String`.
+///
+/// Created by [`LineEnding::display`].
+#[derive(Debug, Clone, Copy)]
+pub struct LineEndingDisplay<'a> {
+    line_ending: LineEnding,
+    text: &'a str,
+}
+
+impl fmt::Display for LineEndingDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let target = self.line_ending.as_str();
+        let mut remaining = self.text;
+
+        while let Some((pos, line_ending)) = find_line_ending(remaining.as_bytes()) {
+            f.write_str(&remaining[..pos])?;
+            f.write_str(target)?;
+            remaining = &remaining[pos + line_ending.as_str().len()..];
+        }
+
+        f.write_str(remaining)
+    }
+}
+
+/// A [`fmt::Write`] adapter that converts the line endings of everything
+/// written through it before passing it to the inner writer.
+///
+/// A CRLF split across two writes (`"\r"` then `"\n"`) is still recognized as a
+/// single line ending. No flushing is required.
+///
+/// # Example
+///
+/// ```
+/// use std::fmt::Write;
+/// use line_ending::{LineEnding, LineEndingWriter};
+///
+/// let mut writer = LineEndingWriter::new(String::new(), LineEnding::CRLF);
+/// write!(writer, "first\nsecond\r").unwrap();
+/// write!(writer, "\nthird\n").unwrap();
+///
+/// assert_eq!(writer.into_inner(), "first\r\nsecond\r\nthird\r\n");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineEndingWriter<W> {
+    inner: W,
+    line_ending: LineEnding,
+    /// Whether the last character written was a `\r`, so that a following
+    /// `\n` completes a CRLF that has already been written.
+    after_cr: bool,
+}
+
+impl<W: fmt::Write> LineEndingWriter<W> {
+    /// Creates a writer that converts line endings to `line_ending`.
+    pub fn new(inner: W, line_ending: LineEnding) -> Self {
+        Self {
+            inner,
+            line_ending,
+            after_cr: false,
+        }
+    }
+
+    /// Returns a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Unwraps the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for LineEndingWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if s.is_empty() {
+            return Ok(());
+        }
+
+        let target = self.line_ending.as_str();
+        let mut remaining = s;
+
+        // Skip the `\n` of a CRLF whose `\r` ended the previous write
+        if self.after_cr && remaining.starts_with('\n') {
+            remaining = &remaining[1..];
+        }
+        self.after_cr = false;
+
+        while let Some((pos, line_ending)) = find_line_ending(remaining.as_bytes()) {
+            self.inner.write_str(&remaining[..pos])?;
+            self.inner.write_str(target)?;
+            remaining = &remaining[pos + line_ending.as_str().len()..];
+
+            // A trailing lone `\r` may be the first half of a split CRLF
+            self.after_cr = line_ending == LineEnding::CR && remaining.is_empty();
+        }
+
+        self.inner.write_str(remaining)
+    }
+}
+
+impl LineEnding {
+    /// Returns a wrapper that displays `text` with its line endings converted
+    /// to this type, writing directly to the formatter without allocating.
+    ///
+    /// The output is identical to [`LineEnding::apply`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let text = "first\nsecond\rthird";
+    /// assert_eq!(
+    ///     format!("{}", LineEnding::CRLF.display(text)),
+    ///     "first\r\nsecond\r\nthird"
+    /// );
+    /// ```
+    pub fn display<'a>(&self, text: &'a str) -> LineEndingDisplay<'a> {
+        LineEndingDisplay {
+            line_ending: *self,
+            text,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::LineEnding;
+
+impl LineEnding {
+    /// Replaces real line endings with their escaped text form, producing a
+    /// single-line string (e.g. for JSON logs or single-line records).
+    ///
+    /// - `\r\n` → `\\r\\n`, `\n` → `\\n`, `\r` → `\\r`
+    /// - `\\` → `\\\\`, so that text which already contains escape-like sequences
+    ///   survives a round trip through [`LineEnding::unescape_line_endings`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::escape_line_endings("a\r\nb\nc"), "a\\r\\nb\\nc");
+    ///
+    /// // A literal backslash followed by `n` is not confused with a line ending
+    /// let text = "path\\nope\n";
+    /// let escaped = LineEnding::escape_line_endings(text);
+    /// assert_eq!(escaped, "path\\\\nope\\n");
+    /// assert_eq!(LineEnding::unescape_line_endings(&escaped), text);
+    /// ```
+    pub fn escape_line_endings(s: &str) -> String {
+        let mut result = String::with_capacity(s.len());
+
+        for ch in s.chars() {
+            match ch {
+                '\\' => result.push_str("\\\\"),
+                '\r' => result.push_str("\\r"),
+                '\n' => result.push_str("\\n"),
+                _ => result.push(ch),
+            }
+        }
+
+        result
+    }
+
+    /// Replaces escaped line endings with real ones; the reverse of
+    /// [`LineEnding::escape_line_endings`].
+    ///
+    /// Backslash parity is respected: `\\\\` is an escaped backslash, so
+    /// `\\\\n` becomes a literal backslash followed by `n`, not a line ending.
+    /// Any other escape sequence (and a trailing lone backslash) is left as-is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::unescape_line_endings("a\\r\\nb\\rc"), "a\r\nb\rc");
+    /// assert_eq!(LineEnding::unescape_line_endings("a\\\\nb"), "a\\nb");
+    /// assert_eq!(LineEnding::unescape_line_endings("tab\\t"), "tab\\t");
+    /// ```
+    pub fn unescape_line_endings(s: &str) -> String {
+        let mut result = String::with_capacity(s.len());
+        let mut chars = s.chars();
+
+        while let Some(ch) = chars.next() {
+            if ch != '\\' {
+                result.push(ch);
+                continue;
+            }
+
+            match chars.next() {
+                Some('\\') => result.push('\\'),
+                Some('r') => result.push('\r'),
+                Some('n') => result.push('\n'),
+                Some(other) => {
+                    result.push('\\');
+                    result.push(other);
+                }
+                None => result.push('\\'),
+            }
+        }
+
+        result
+    }
+}
@@ -0,0 +1,158 @@
+use crate::LineEnding;
+use std::iter::Peekable;
+
+/// An item produced by [`EscapeAwareLineEndings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScanItem {
+    /// A content character, including escape characters that do not form an
+    /// escaped line ending (e.g. both characters of an escaped backslash).
+    Char(char),
+    /// An actual line ending.
+    LineEnding(LineEnding),
+    /// An escaped line ending (`\\r\\n`, `\\n`, or `\\r` as text). The escape
+    /// sequence is consumed; write [`LineEnding::as_str`] escaped to reproduce it.
+    EscapedLineEnding(LineEnding),
+}
+
+/// An iterator adapter over characters that reports line endings, tracking
+/// escape state across the whole stream.
+///
+/// Unlike [`PeekableLineEndingExt::consume_line_ending`](crate::PeekableLineEndingExt::consume_line_ending),
+/// which only looks one character ahead, this respects backslash parity: in
+/// `\\\\n` (an escaped backslash followed by `n`) the `n` is content, while in
+/// `\\\\\\n` the final `\\n` is an escaped line ending.
+///
+/// The escape character defaults to `\`, can be changed, or escape detection
+/// can be disabled entirely with [`EscapeAwareLineEndings::escape_char`].
+/// Only `peek` is used for lookahead, so the source iterator need not be `Clone`.
+///
+/// # Example
+///
+/// ```
+/// use line_ending::{EscapeAwareLineEndings, LineEnding, ScanItem};
+///
+/// let items: Vec<ScanItem> = EscapeAwareLineEndings::new("a\\r\\n\\\\n\r\n".chars()).collect();
+///
+/// assert_eq!(
+///     items,
+///     vec![
+///         ScanItem::Char('a'),
+///         ScanItem::EscapedLineEnding(LineEnding::CRLF),
+///         ScanItem::Char('\\'),
+///         ScanItem::Char('\\'),
+///         ScanItem::Char('n'),
+///         ScanItem::LineEnding(LineEnding::CRLF),
+///     ]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct EscapeAwareLineEndings<I: Iterator<Item = char>> {
+    iter: Peekable<I>,
+    escape_char: Option<char>,
+    /// The next character is escaped and must be yielded as content.
+    literal_next: bool,
+    /// An escape character has already been consumed and starts the next item.
+    pending_escape: bool,
+}
+
+impl<I: Iterator<Item = char>> EscapeAwareLineEndings<I> {
+    /// Creates an adapter using `\` as the escape character.
+    pub fn new(iter: I) -> Self {
+        Self {
+            iter: iter.peekable(),
+            escape_char: Some('\\'),
+            literal_next: false,
+            pending_escape: false,
+        }
+    }
+
+    /// Sets the escape character, or disables escape detection with `None`
+    /// (in which case every `\r` and `\n` is an actual line ending).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{EscapeAwareLineEndings, LineEnding, ScanItem};
+    ///
+    /// let items: Vec<ScanItem> = EscapeAwareLineEndings::new("^n".chars())
+    ///     .escape_char(Some('^'))
+    ///     .collect();
+    /// assert_eq!(items, vec![ScanItem::EscapedLineEnding(LineEnding::LF)]);
+    /// ```
+    pub fn escape_char(mut self, escape_char: Option<char>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
+    fn is_escape(&self, ch: char) -> bool {
+        self.escape_char == Some(ch)
+    }
+
+    /// Handles the item starting with an already-consumed escape character.
+    fn after_escape(&mut self, escape: char) -> ScanItem {
+        match self.iter.peek().copied() {
+            Some(next) if self.is_escape(next) => {
+                // An escaped escape character: both are content
+                self.literal_next = true;
+                ScanItem::Char(escape)
+            }
+            Some('n') => {
+                self.iter.next();
+                ScanItem::EscapedLineEnding(LineEnding::LF)
+            }
+            Some('r') => {
+                self.iter.next();
+
+                match self.iter.peek().copied() {
+                    Some(next) if self.is_escape(next) => {
+                        self.iter.next();
+                        if self.iter.peek() == Some(&'n') {
+                            self.iter.next();
+                            ScanItem::EscapedLineEnding(LineEnding::CRLF)
+                        } else {
+                            // The consumed escape belongs to the next item
+                            self.pending_escape = true;
+                            ScanItem::EscapedLineEnding(LineEnding::CR)
+                        }
+                    }
+                    _ => ScanItem::EscapedLineEnding(LineEnding::CR),
+                }
+            }
+            _ => ScanItem::Char(escape),
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for EscapeAwareLineEndings<I> {
+    type Item = ScanItem;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending_escape {
+            self.pending_escape = false;
+            let escape = self
+                .escape_char
+                .expect("pending escape implies an escape char");
+            return Some(self.after_escape(escape));
+        }
+
+        let ch = self.iter.next()?;
+
+        if self.literal_next {
+            self.literal_next = false;
+            return Some(ScanItem::Char(ch));
+        }
+
+        let item = match ch {
+            _ if self.is_escape(ch) => self.after_escape(ch),
+            '\r' if self.iter.peek() == Some(&'\n') => {
+                self.iter.next();
+                ScanItem::LineEnding(LineEnding::CRLF)
+            }
+            '\r' => ScanItem::LineEnding(LineEnding::CR),
+            '\n' => ScanItem::LineEnding(LineEnding::LF),
+            _ => ScanItem::Char(ch),
+        };
+
+        Some(item)
+    }
+}
@@ -0,0 +1,317 @@
+use crate::LineEnding;
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::ops::Range;
+
+/// Size of the blocks read while building a [`FileLineIndex`].
+const BLOCK_SIZE: usize = 64 * 1024;
+
+/// An index of line starts in a seekable source (such as a `File`), for
+/// reading arbitrary line ranges without reading the whole source.
+///
+/// This is an index-once design: building the index reads the whole source in
+/// a single streaming pass that never holds more than one block in memory.
+/// Afterwards, every [`FileLineIndex::read_lines`] call seeks directly to the
+/// requested lines and reads only their bytes, so the cost of the full pass is
+/// paid once and shared by all later lookups. For a single lookup near the
+/// start of a large source, [`FileLineIndex::scan_lines`] reads only up to the
+/// end of the requested range instead.
+///
+/// Line semantics match [`LineIndex`](crate::LineIndex): LF, CRLF, and CR are
+/// all recognized (a CRLF split across blocks is still a single line ending),
+/// and a trailing line ending starts a final empty line.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use line_ending::{FileLineIndex, LineEnding};
+///
+/// let mut source = Cursor::new("zero\r\none\ntwo\rthree");
+/// let index = FileLineIndex::from_reader(&mut source).unwrap();
+///
+/// assert_eq!(index.line_count(), 4);
+/// assert_eq!(index.line_ending(0), Some(LineEnding::CRLF));
+/// assert_eq!(index.read_lines(&mut source, 1..3).unwrap(), vec!["one", "two"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileLineIndex {
+    /// Byte offset of the start of each line.
+    line_starts: Vec<u64>,
+    /// The line ending terminating each line (`None` for the final line).
+    line_endings: Vec<Option<LineEnding>>,
+    len: u64,
+}
+
+impl FileLineIndex {
+    /// Builds an index by reading `reader` from its current position to the end.
+    ///
+    /// Offsets are counted from the position at which reading started, which
+    /// should be the start of the source, since [`FileLineIndex::read_lines`]
+    /// seeks to them as absolute offsets.
+    pub fn from_reader<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut line_starts = vec![0];
+        let mut line_endings = Vec::new();
+        let mut buffer = vec![0; BLOCK_SIZE];
+        let mut offset: u64 = 0;
+        let mut pending_cr = false;
+
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            for (i, &byte) in buffer[..read].iter().enumerate() {
+                let position = offset + i as u64;
+
+                if pending_cr {
+                    pending_cr = false;
+                    if byte == b'\n' {
+                        line_starts.push(position + 1);
+                        line_endings.push(Some(LineEnding::CRLF));
+                        continue;
+                    }
+                    line_starts.push(position);
+                    line_endings.push(Some(LineEnding::CR));
+                }
+
+                match byte {
+                    b'\r' => pending_cr = true,
+                    b'\n' => {
+                        line_starts.push(position + 1);
+                        line_endings.push(Some(LineEnding::LF));
+                    }
+                    _ => {}
+                }
+            }
+
+            offset += read as u64;
+        }
+
+        if pending_cr {
+            line_starts.push(offset);
+            line_endings.push(Some(LineEnding::CR));
+        }
+        line_endings.push(None);
+
+        Ok(Self {
+            line_starts,
+            line_endings,
+            len: offset,
+        })
+    }
+
+    /// Reads the lines in `lines` (excluding line endings) with a forward scan
+    /// that stops as soon as the end of the range is reached, without building
+    /// an index.
+    ///
+    /// Only the source up to the end of the requested range is read (in
+    /// blocks), so this suits one-off lookups near the start of a large
+    /// source. Line numbering matches [`FileLineIndex::read_lines`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if the range extends past the
+    /// last line, and [`io::ErrorKind::InvalidData`] if a line is not valid
+    /// UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use line_ending::FileLineIndex;
+    ///
+    /// let source = Cursor::new("zero\r\none\ntwo\rthree");
+    /// assert_eq!(FileLineIndex::scan_lines(source, 1..3).unwrap(), vec!["one", "two"]);
+    /// ```
+    pub fn scan_lines<R: Read>(reader: R, lines: Range<usize>) -> io::Result<Vec<String>> {
+        if lines.start > lines.end {
+            return Err(out_of_bounds(&lines, None));
+        }
+
+        let mut reader = BufReader::with_capacity(BLOCK_SIZE, reader);
+        let mut result = Vec::with_capacity(lines.len());
+        let mut current = Vec::new();
+        let mut line = 0;
+        let mut skip_lf = false;
+
+        while line < lines.end {
+            let block = match reader.fill_buf() {
+                Ok([]) => break,
+                Ok(block) => block,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            let mut used = 0;
+            for &byte in block {
+                used += 1;
+
+                // The `\n` of a CRLF whose `\r` already ended the line
+                if std::mem::take(&mut skip_lf) && byte == b'\n' {
+                    continue;
+                }
+
+                match byte {
+                    b'\r' | b'\n' => {
+                        if line >= lines.start {
+                            result.push(into_line(std::mem::take(&mut current))?);
+                        }
+                        line += 1;
+                        skip_lf = byte == b'\r';
+
+                        if line == lines.end {
+                            break;
+                        }
+                    }
+                    _ if line >= lines.start => current.push(byte),
+                    _ => {}
+                }
+            }
+            reader.consume(used);
+        }
+
+        // The final line runs to the end of the source
+        if line < lines.end {
+            if line >= lines.start {
+                result.push(into_line(current)?);
+            }
+            line += 1;
+        }
+
+        if line < lines.end {
+            return Err(out_of_bounds(&lines, Some(line)));
+        }
+
+        Ok(result)
+    }
+
+    /// Returns the number of lines in the source.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the length, in bytes, of the indexed source.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if the indexed source is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the byte range of `line`'s content, excluding its line ending.
+    pub fn line_range(&self, line: usize) -> Option<Range<u64>> {
+        let range = self.line_range_with_ending(line)?;
+        let ending_len = self.line_endings[line].map_or(0, |le| le.as_str().len() as u64);
+
+        Some(range.start..range.end - ending_len)
+    }
+
+    /// Returns the byte range of `line`, including its line ending.
+    pub fn line_range_with_ending(&self, line: usize) -> Option<Range<u64>> {
+        let start = *self.line_starts.get(line)?;
+        let end = self.line_starts.get(line + 1).copied().unwrap_or(self.len);
+
+        Some(start..end)
+    }
+
+    /// Returns the line ending terminating `line`.
+    ///
+    /// Returns `None` for the final line (which has no line ending) and for
+    /// lines that do not exist.
+    pub fn line_ending(&self, line: usize) -> Option<LineEnding> {
+        self.line_endings.get(line).copied().flatten()
+    }
+
+    /// Seeks to and reads the lines in `lines`, excluding line endings.
+    ///
+    /// `reader` must be the source the index was built from (or an identical
+    /// copy); only the bytes of the requested lines are read.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`io::ErrorKind::InvalidInput`] if the range extends past the
+    /// last line, and [`io::ErrorKind::InvalidData`] if a line is not valid
+    /// UTF-8.
+    pub fn read_lines<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        lines: Range<usize>,
+    ) -> io::Result<Vec<String>> {
+        self.read_lines_impl(reader, lines, false)
+    }
+
+    /// Seeks to and reads the lines in `lines`, including line endings.
+    ///
+    /// See [`FileLineIndex::read_lines`].
+    pub fn read_lines_with_endings<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        lines: Range<usize>,
+    ) -> io::Result<Vec<String>> {
+        self.read_lines_impl(reader, lines, true)
+    }
+
+    fn read_lines_impl<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        lines: Range<usize>,
+        with_endings: bool,
+    ) -> io::Result<Vec<String>> {
+        if lines.start > lines.end || lines.end > self.line_count() {
+            return Err(out_of_bounds(&lines, Some(self.line_count())));
+        }
+
+        if lines.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Read the whole span covering the requested lines in one go
+        let span_start = self.line_starts[lines.start];
+        let span_end = self.line_starts.get(lines.end).copied().unwrap_or(self.len);
+
+        let mut span = vec![0; (span_end - span_start) as usize];
+        reader.seek(SeekFrom::Start(span_start))?;
+        reader.read_exact(&mut span)?;
+
+        lines
+            .map(|line| {
+                let range = if with_endings {
+                    self.line_range_with_ending(line)
+                } else {
+                    self.line_range(line)
+                }
+                .expect("line is within bounds");
+
+                let bytes =
+                    &span[(range.start - span_start) as usize..(range.end - span_start) as usize];
+
+                into_line(bytes.to_vec())
+            })
+            .collect()
+    }
+}
+
+/// Converts the bytes of a line to a `String`, reporting invalid UTF-8 as
+/// [`io::ErrorKind::InvalidData`].
+fn into_line(bytes: Vec<u8>) -> io::Result<String> {
+    String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Builds the error for a line range that is reversed or extends past the last
+/// of `line_count` lines.
+fn out_of_bounds(lines: &Range<usize>, line_count: Option<usize>) -> io::Error {
+    let message = match line_count {
+        Some(line_count) => format!(
+            "line range {:?} is out of bounds for {} lines",
+            lines, line_count
+        ),
+        None => format!("line range {:?} is reversed", lines),
+    };
+
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
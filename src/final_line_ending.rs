@@ -0,0 +1,145 @@
+use crate::line_ending::score_bytes;
+use crate::LineEnding;
+
+/// Policy for the line endings at the very end of a text.
+///
+/// Used with [`LineEnding::fix_final_line_ending`] and
+/// [`LineEnding::apply_with_final_line_ending`], and checked with
+/// [`FinalLineEnding::is_satisfied_by`].
+///
+/// Empty texts are left empty by every policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum FinalLineEnding {
+    /// Leave the end of the text unchanged.
+    #[default]
+    Preserve,
+    /// Add a line ending if the text does not already end with one.
+    Ensure,
+    /// Remove all trailing line endings, so the text ends with content.
+    Strip,
+    /// Collapse trailing blank lines, so the text ends with at most one line ending.
+    Collapse,
+    /// End the text with exactly one line ending (`Ensure` and `Collapse` combined).
+    Single,
+}
+
+impl FinalLineEnding {
+    /// Returns `true` if `s` already conforms to this policy, i.e. fixing it
+    /// would leave it unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::FinalLineEnding;
+    ///
+    /// assert!(FinalLineEnding::Single.is_satisfied_by("a\r\nb\r\n"));
+    /// assert!(!FinalLineEnding::Single.is_satisfied_by("a\r\nb"));
+    /// assert!(!FinalLineEnding::Single.is_satisfied_by("a\r\nb\r\n\r\n"));
+    /// ```
+    pub fn is_satisfied_by(&self, s: &str) -> bool {
+        let trailing_count = trailing_line_ending_count(s);
+
+        match self {
+            Self::Preserve => true,
+            Self::Ensure => s.is_empty() || trailing_count > 0,
+            Self::Strip => trailing_count == 0,
+            Self::Collapse => trailing_count <= 1,
+            Self::Single => s.is_empty() || trailing_count == 1,
+        }
+    }
+}
+
+/// Splits `s` into its content and its run of trailing line endings.
+fn split_trailing(s: &str) -> (&str, &str) {
+    let content = s.trim_end_matches(['\r', '\n']);
+    (content, &s[content.len()..])
+}
+
+/// Counts the line endings in the run at the end of `s`.
+fn trailing_line_ending_count(s: &str) -> usize {
+    let (_, trailing) = split_trailing(s);
+    score_bytes(trailing.as_bytes()).values().sum()
+}
+
+/// Returns the first line ending in a non-empty run of line endings.
+fn first_line_ending(trailing: &str) -> &str {
+    if trailing.starts_with("\r\n") {
+        &trailing[..2]
+    } else {
+        &trailing[..1]
+    }
+}
+
+impl LineEnding {
+    /// Applies a [`FinalLineEnding`] policy to the end of `s`.
+    ///
+    /// When a line ending has to be added, `self` is used. When trailing line
+    /// endings are collapsed, the first one is kept as-is. To use the text's own
+    /// style, call this on the detected line ending (`LineEnding::from(s)`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{FinalLineEnding, LineEnding};
+    ///
+    /// let text = "first\r\nsecond";
+    /// let detected = LineEnding::from(text);
+    /// assert_eq!(
+    ///     detected.fix_final_line_ending(text, FinalLineEnding::Ensure),
+    ///     "first\r\nsecond\r\n"
+    /// );
+    ///
+    /// let text = "first\nsecond\n\n\n";
+    /// assert_eq!(
+    ///     LineEnding::LF.fix_final_line_ending(text, FinalLineEnding::Collapse),
+    ///     "first\nsecond\n"
+    /// );
+    /// assert_eq!(
+    ///     LineEnding::LF.fix_final_line_ending(text, FinalLineEnding::Strip),
+    ///     "first\nsecond"
+    /// );
+    /// ```
+    pub fn fix_final_line_ending(&self, s: &str, policy: FinalLineEnding) -> String {
+        if s.is_empty() {
+            return String::new();
+        }
+
+        let (content, trailing) = split_trailing(s);
+
+        match policy {
+            FinalLineEnding::Preserve => s.to_string(),
+            FinalLineEnding::Ensure if trailing.is_empty() => format!("{}{}", s, self.as_str()),
+            FinalLineEnding::Ensure => s.to_string(),
+            FinalLineEnding::Strip => content.to_string(),
+            FinalLineEnding::Collapse if trailing.is_empty() => s.to_string(),
+            FinalLineEnding::Collapse => format!("{}{}", content, first_line_ending(trailing)),
+            FinalLineEnding::Single if trailing.is_empty() => format!("{}{}", s, self.as_str()),
+            FinalLineEnding::Single => format!("{}{}", content, first_line_ending(trailing)),
+        }
+    }
+
+    /// Applies this line ending type to `s` (see [`LineEnding::apply`]), then
+    /// applies a [`FinalLineEnding`] policy to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{FinalLineEnding, LineEnding};
+    ///
+    /// assert_eq!(
+    ///     LineEnding::CRLF.apply_with_final_line_ending("a\nb\rc", FinalLineEnding::Single),
+    ///     "a\r\nb\r\nc\r\n"
+    /// );
+    /// ```
+    pub fn apply_with_final_line_ending(&self, s: &str, policy: FinalLineEnding) -> String {
+        let converted = self.apply(s);
+
+        if policy.is_satisfied_by(&converted) {
+            converted
+        } else {
+            self.fix_final_line_ending(&converted, policy)
+        }
+    }
+}
@@ -2,7 +2,71 @@
 doc_comment::doctest!("../README.md");
 
 pub mod line_ending;
-pub use line_ending::{LineEnding, LineEndingScores};
+pub use line_ending::{LineEnding, LineEndingScores, ParseLineEndingError};
+
+#[doc(hidden)]
+pub mod assertions;
+
+pub mod bytes_ext;
+pub use bytes_ext::{ByteLinesAnyEnding, BytesLineEndingExt};
+
+pub mod conversion_stats;
+pub use conversion_stats::ConversionStats;
+
+pub mod csv_records;
+pub use csv_records::{CsvRecord, CsvRecords};
+
+pub mod cursor;
+pub use cursor::{LineTrackingCursor, Position, Span};
+
+pub mod display;
+pub use display::{LineEndingDisplay, LineEndingWriter};
+
+mod escape;
+
+pub mod escape_aware;
+pub use escape_aware::{EscapeAwareLineEndings, ScanItem};
+
+pub mod file_line_index;
+pub use file_line_index::FileLineIndex;
+
+pub mod final_line_ending;
+pub use final_line_ending::FinalLineEnding;
+
+pub mod line_ending_policy;
+pub use line_ending_policy::LineEndingPolicy;
+
+pub mod line_index;
+pub use line_index::{ColumnUnit, LineCol, LineIndex};
+
+pub mod logical_lines;
+pub use logical_lines::{LogicalLine, LogicalLines};
+
+pub mod lone_cr;
+pub use lone_cr::LoneCr;
+
+pub mod malformed;
+pub use malformed::{LineEndingReport, MalformedLineEnding, MalformedLineEndingScores};
+
+pub mod normalized_str;
+pub use normalized_str::NormalizedStr;
+
+pub mod offset_map;
+pub use offset_map::OffsetMap;
 
 pub mod peekable_ext;
-pub use peekable_ext::PeekableLineEndingExt;
+pub use peekable_ext::{LineEndingUnit, PeekableCharIndicesLineEndingExt, PeekableLineEndingExt};
+
+pub mod reverse_lines;
+pub use reverse_lines::ReverseLines;
+
+pub mod str_ext;
+pub use str_ext::{LinesAnyEnding, StrLineEndingExt};
+
+mod trailing_whitespace;
+
+pub mod visible;
+pub use visible::{Markers, VisibleLineEndings};
+
+#[cfg(feature = "serde")]
+mod serde_support;
@@ -1,4 +1,7 @@
 use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
 
 /// Enum representing the detected line ending style.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -20,6 +23,9 @@ pub enum LineEnding {
 ///
 /// This is used in functions like [`LineEnding::score_mixed_types`] to track
 /// the distribution of line endings in a text.
+///
+/// With the `serde` feature enabled, scores serialize as a map keyed by the
+/// canonical line ending names (e.g. `{"lf": 4, "crlf": 1, "cr": 1}`).
 pub type LineEndingScores = HashMap<LineEnding, usize>;
 
 impl From<&str> for LineEnding {
@@ -37,28 +43,108 @@ impl From<&str> for LineEnding {
     /// assert_eq!(LineEnding::from(sample), LineEnding::CRLF);
     /// ```
     fn from(s: &str) -> Self {
-        let scores = Self::score_mixed_types(s);
+        predominant(&Self::score_mixed_types(s))
+    }
+}
 
-        let crlf_score = *scores.get(&Self::CRLF).unwrap_or(&0);
-        let cr_score = *scores.get(&Self::CR).unwrap_or(&0);
-        let lf_score = *scores.get(&Self::LF).unwrap_or(&0);
+/// Error returned when a string cannot be parsed into a [`LineEnding`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLineEndingError {
+    input: String,
+}
 
-        // Select the highest count
-        let max_score = crlf_score.max(cr_score).max(lf_score);
+impl ParseLineEndingError {
+    /// Returns the input that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
 
-        if max_score == 0 || crlf_score == max_score {
-            // `CRLF` is chosen as a tie-breaker because it represents both `CR`
-            // and `LF`, making it the most inclusive option
-            Self::CRLF
-        } else if cr_score == max_score {
-            Self::CR
-        } else {
-            Self::LF
+/// Description of the values accepted by `LineEnding`'s `FromStr`
+/// implementation, shared by parse and deserialization error messages.
+pub(crate) const EXPECTED: &str = "a line ending name (\"lf\", \"crlf\", \"cr\", \"unix\", \"windows\", \"mac\", or \"native\"), an escaped line ending (\"\\n\", \"\\r\\n\", or \"\\r\"), or a literal line ending";
+
+impl fmt::Display for ParseLineEndingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unrecognized line ending {:?}; expected {}",
+            self.input, EXPECTED
+        )
+    }
+}
+
+impl Error for ParseLineEndingError {}
+
+impl FromStr for LineEnding {
+    type Err = ParseLineEndingError;
+
+    /// Parses a line ending from its name or its literal/escaped representation.
+    ///
+    /// Accepted values (case-insensitive):
+    /// - `lf`, `unix`, the escaped `\\n`, or an actual `\n` → `LF`
+    /// - `crlf`, `windows`, the escaped `\\r\\n`, or an actual `\r\n` → `CRLF`
+    /// - `cr`, `mac`, the escaped `\\r`, or an actual `\r` → `CR`
+    /// - `native` → [`LineEnding::from_current_platform`]
+    ///
+    /// `mac` refers to classic Mac OS (pre-OS X); modern macOS uses `LF`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// assert_eq!("crlf".parse::<LineEnding>(), Ok(LineEnding::CRLF));
+    /// assert_eq!("Unix".parse::<LineEnding>(), Ok(LineEnding::LF));
+    /// assert_eq!("\\r".parse::<LineEnding>(), Ok(LineEnding::CR));
+    /// assert!("auto".parse::<LineEnding>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "lf" | "unix" | "\\n" | "\n" => Ok(Self::LF),
+            "crlf" | "windows" | "\\r\\n" | "\r\n" => Ok(Self::CRLF),
+            "cr" | "mac" | "\\r" | "\r" => Ok(Self::CR),
+            "native" => Ok(Self::from_current_platform()),
+            _ => Err(ParseLineEndingError {
+                input: s.to_string(),
+            }),
         }
     }
 }
 
+impl fmt::Display for LineEnding {
+    /// Writes the human-readable name of the line ending (`LF`, `CRLF`, or `CR`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::CRLF.to_string(), "CRLF");
+    /// assert_eq!(format!("{}", LineEnding::LF), "LF");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::LF => "LF",
+            Self::CRLF => "CRLF",
+            Self::CR => "CR",
+        })
+    }
+}
+
 impl LineEnding {
+    /// All line ending variants, in tie-breaker order (`CRLF`, `CR`, `LF`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::ALL.len(), 3);
+    /// assert!(LineEnding::ALL.contains(&LineEnding::LF));
+    /// ```
+    pub const ALL: [LineEnding; 3] = [Self::CRLF, Self::CR, Self::LF];
+
     /// Detects the default line ending based on the current operating system.
     ///
     /// - **Unix-based (Linux/macOS):** Uses LF (`\n`).
@@ -86,10 +172,10 @@ impl LineEnding {
     /// (a `HashMap<LineEnding, usize>`) containing the number of times each
     /// line ending appears.
     ///
-    /// - `CRLF (\r\n)` is counted first to ensure `\r` inside `\r\n` is not
-    ///   double-counted.
-    /// - `CR (\r)` is counted separately, subtracting occurrences of `CRLF`.
-    /// - `LF (\n)` is counted separately, also subtracting occurrences of `CRLF`.
+    /// - `CRLF (\r\n)` is counted as a single line ending, so the `\r` and `\n`
+    ///   inside it are not double-counted.
+    /// - `CR (\r)` counts only lone carriage returns.
+    /// - `LF (\n)` counts only line feeds not preceded by `\r`.
     ///
     /// # Example
     ///
@@ -104,21 +190,7 @@ impl LineEnding {
     /// assert_eq!(scores[&LineEnding::CR], 1);
     /// ```
     pub fn score_mixed_types(s: &str) -> LineEndingScores {
-        let crlf_score = Self::CRLF.split_with(s).len().saturating_sub(1);
-
-        // Ensure CR is not double-counted when it's part of CRLF
-        let cr_score = Self::CR.split_with(s).len().saturating_sub(1) - crlf_score;
-
-        // Ensure LF is not double-counted when it's part of CRLF
-        let lf_score = Self::LF.split_with(s).len().saturating_sub(1) - crlf_score;
-
-        [
-            (LineEnding::CRLF, crlf_score),
-            (LineEnding::CR, cr_score),
-            (LineEnding::LF, lf_score),
-        ]
-        .into_iter()
-        .collect()
+        score_bytes(s.as_bytes())
     }
 
     /// Returns the string representation of the line ending (`\n`, `\r\n`, or `\r`).
@@ -140,8 +212,53 @@ impl LineEnding {
         }
     }
 
+    /// Returns the canonical lowercase name of the line ending (`lf`, `crlf`, or `cr`).
+    ///
+    /// This is the form used for configuration files and is accepted by
+    /// [`LineEnding::from_str`](#impl-FromStr-for-LineEnding).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::CRLF.name(), "crlf");
+    /// assert_eq!(LineEnding::LF.name().parse::<LineEnding>(), Ok(LineEnding::LF));
+    /// ```
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::LF => "lf",
+            Self::CRLF => "crlf",
+            Self::CR => "cr",
+        }
+    }
+
+    /// Returns the character representation of the line ending, or `None` for
+    /// `CRLF`, which is composed of two characters.
+    ///
+    /// This is the non-panicking counterpart of [`LineEnding::as_char`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// assert_eq!(LineEnding::LF.try_as_char(), Some('\n'));
+    /// assert_eq!(LineEnding::CR.try_as_char(), Some('\r'));
+    /// assert_eq!(LineEnding::CRLF.try_as_char(), None);
+    /// ```
+    pub fn try_as_char(&self) -> Option<char> {
+        match self {
+            Self::LF => Some('\n'),
+            Self::CR => Some('\r'),
+            Self::CRLF => None,
+        }
+    }
+
     /// Returns the character representation of the line ending if it is a single character.
     ///
+    /// Prefer [`LineEnding::try_as_char`] when the line ending may be `CRLF`.
+    ///
     /// # Panics
     ///
     /// Panics if the line ending is CRLF, because CRLF is composed of two characters
@@ -158,11 +275,8 @@ impl LineEnding {
     /// // LineEnding::CRLF.as_char();
     /// ```
     pub fn as_char(&self) -> char {
-        match self {
-            Self::LF => '\n',
-            Self::CR => '\r',
-            Self::CRLF => panic!("CRLF cannot be represented as a single character"),
-        }
+        self.try_as_char()
+            .expect("CRLF cannot be represented as a single character")
     }
 
     /// Converts all line endings in a string to LF (`\n`) for consistent processing.
@@ -266,4 +380,202 @@ impl LineEnding {
         let normalized = Self::normalize(s);
         normalized.replace("\n", self.as_str())
     }
+
+    /// Applies this line ending type to a `String` in place.
+    ///
+    /// Converting to `LF` or `CR` never grows the string, so no reallocation
+    /// occurs. Converting to `CRLF` counts the line endings first and grows the
+    /// buffer at most once to the exact required size.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let mut text = String::from("first\r\nsecond\rthird\n");
+    /// LineEnding::LF.apply_in_place(&mut text);
+    /// assert_eq!(text, "first\nsecond\nthird\n");
+    ///
+    /// LineEnding::CRLF.apply_in_place(&mut text);
+    /// assert_eq!(text, "first\r\nsecond\r\nthird\r\n");
+    /// ```
+    pub fn apply_in_place(&self, s: &mut String) {
+        let mut bytes = std::mem::take(s).into_bytes();
+        self.apply_in_place_bytes(&mut bytes);
+
+        // Only ASCII line ending bytes are rewritten, so the buffer remains valid UTF-8
+        *s = String::from_utf8(bytes).expect("line ending conversion preserves UTF-8");
+    }
+
+    /// Applies this line ending type to a byte buffer in place.
+    ///
+    /// Behaves like [`LineEnding::apply_in_place`], but operates on raw bytes,
+    /// so the buffer does not need to be valid UTF-8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let mut bytes = b"first\nsecond\r\nthird".to_vec();
+    /// LineEnding::CR.apply_in_place_bytes(&mut bytes);
+    /// assert_eq!(bytes, b"first\rsecond\rthird");
+    /// ```
+    pub fn apply_in_place_bytes(&self, bytes: &mut Vec<u8>) {
+        match self {
+            Self::LF => collapse_line_endings(bytes, b'\n'),
+            Self::CR => collapse_line_endings(bytes, b'\r'),
+            Self::CRLF => expand_to_crlf(bytes),
+        }
+    }
+}
+
+/// Selects the line ending with the highest score, preferring `CRLF` on ties
+/// and when there are no line endings at all.
+pub(crate) fn predominant(scores: &LineEndingScores) -> LineEnding {
+    let crlf_score = *scores.get(&LineEnding::CRLF).unwrap_or(&0);
+    let cr_score = *scores.get(&LineEnding::CR).unwrap_or(&0);
+    let lf_score = *scores.get(&LineEnding::LF).unwrap_or(&0);
+
+    // Select the highest count
+    let max_score = crlf_score.max(cr_score).max(lf_score);
+
+    if max_score == 0 || crlf_score == max_score {
+        // `CRLF` is chosen as a tie-breaker because it represents both `CR`
+        // and `LF`, making it the most inclusive option
+        LineEnding::CRLF
+    } else if cr_score == max_score {
+        LineEnding::CR
+    } else {
+        LineEnding::LF
+    }
+}
+
+/// Counts occurrences of each line ending type in a single pass over `bytes`.
+pub(crate) fn score_bytes(bytes: &[u8]) -> LineEndingScores {
+    let mut crlf_score = 0;
+    let mut cr_score = 0;
+    let mut lf_score = 0;
+
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => {
+                crlf_score += 1;
+                i += 1;
+            }
+            b'\r' => cr_score += 1,
+            b'\n' => lf_score += 1,
+            _ => {}
+        }
+        i += 1;
+    }
+
+    [
+        (LineEnding::CRLF, crlf_score),
+        (LineEnding::CR, cr_score),
+        (LineEnding::LF, lf_score),
+    ]
+    .into_iter()
+    .collect()
+}
+
+/// Rewrites every line ending in `bytes` as the single byte `target`, compacting
+/// the buffer from front to back. The result is never longer than the input.
+fn collapse_line_endings(bytes: &mut Vec<u8>, target: u8) {
+    let len = bytes.len();
+    let mut read = 0;
+    let mut write = 0;
+
+    while read < len {
+        let byte = bytes[read];
+        read += 1;
+
+        if byte == b'\r' || byte == b'\n' {
+            if byte == b'\r' && read < len && bytes[read] == b'\n' {
+                read += 1; // Consume the `\n` of a CRLF
+            }
+            bytes[write] = target;
+        } else {
+            bytes[write] = byte;
+        }
+        write += 1;
+    }
+
+    bytes.truncate(write);
+}
+
+/// Rewrites every line ending in `bytes` as CRLF, growing the buffer once and
+/// filling it from back to front so no byte is overwritten before it is read.
+fn expand_to_crlf(bytes: &mut Vec<u8>) {
+    let scores = score_bytes(bytes);
+    let extra = scores[&LineEnding::CR] + scores[&LineEnding::LF];
+    if extra == 0 {
+        return;
+    }
+
+    let mut read = bytes.len();
+    let mut write = read + extra;
+    bytes.resize(write, 0);
+
+    while read > 0 {
+        read -= 1;
+        let byte = bytes[read];
+
+        if byte == b'\r' || byte == b'\n' {
+            if byte == b'\n' && read > 0 && bytes[read - 1] == b'\r' {
+                read -= 1; // Consume the `\r` of a CRLF
+            }
+            write -= 2;
+            bytes[write] = b'\r';
+            bytes[write + 1] = b'\n';
+        } else {
+            write -= 1;
+            bytes[write] = byte;
+        }
+    }
+}
+
+/// Finds the first line ending in `bytes`, returning its byte offset and type.
+///
+/// A `\r` immediately followed by `\n` is reported as a single `CRLF`.
+pub(crate) fn find_line_ending(bytes: &[u8]) -> Option<(usize, LineEnding)> {
+    let pos = bytes.iter().position(|&b| b == b'\r' || b == b'\n')?;
+
+    let line_ending = match (bytes[pos], bytes.get(pos + 1)) {
+        (b'\r', Some(b'\n')) => LineEnding::CRLF,
+        (b'\r', _) => LineEnding::CR,
+        _ => LineEnding::LF,
+    };
+
+    Some((pos, line_ending))
+}
+
+/// Splits the first line off `bytes`, returning the end of its content and the
+/// start of the next line.
+pub(crate) fn split_first_line(bytes: &[u8]) -> (usize, usize) {
+    match find_line_ending(bytes) {
+        Some((pos, line_ending)) => (pos, pos + line_ending.as_str().len()),
+        None => (bytes.len(), bytes.len()),
+    }
+}
+
+/// Splits the last line off `bytes`, returning the start of its content and the
+/// end of its content. A single trailing line ending is treated as terminating
+/// the last line rather than starting a new, empty one.
+pub(crate) fn split_last_line(bytes: &[u8]) -> (usize, usize) {
+    let end = if bytes.ends_with(b"\r\n") {
+        bytes.len() - 2
+    } else if bytes.ends_with(b"\r") || bytes.ends_with(b"\n") {
+        bytes.len() - 1
+    } else {
+        bytes.len()
+    };
+
+    let start = bytes[..end]
+        .iter()
+        .rposition(|&b| b == b'\r' || b == b'\n')
+        .map_or(0, |pos| pos + 1);
+
+    (start, end)
 }
@@ -0,0 +1,78 @@
+use crate::{LineEnding, ParseLineEndingError};
+use std::fmt;
+use std::str::FromStr;
+
+/// A configured line ending: either a fixed [`LineEnding`], or `Auto` to use
+/// whichever line ending the text being processed already uses.
+///
+/// This is the type to store in configuration files, where `"auto"` is a
+/// common value that a plain `LineEnding` cannot represent. With the `serde`
+/// feature enabled, it (de)serializes as `"auto"` or as a line ending name.
+///
+/// # Example
+///
+/// ```
+/// use line_ending::{LineEnding, LineEndingPolicy};
+///
+/// let policy: LineEndingPolicy = "auto".parse().unwrap();
+/// assert_eq!(policy.resolve("a\r\nb\r\n"), LineEnding::CRLF);
+///
+/// let policy: LineEndingPolicy = "lf".parse().unwrap();
+/// assert_eq!(policy, LineEndingPolicy::Fixed(LineEnding::LF));
+/// assert_eq!(policy.resolve("a\r\nb\r\n"), LineEnding::LF);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum LineEndingPolicy {
+    /// Detect the line ending from the text (see `LineEnding::from`).
+    #[default]
+    Auto,
+    /// Always use the given line ending.
+    Fixed(LineEnding),
+}
+
+impl LineEndingPolicy {
+    /// Returns the line ending to use for `s`.
+    pub fn resolve(&self, s: &str) -> LineEnding {
+        match self {
+            Self::Auto => LineEnding::from(s),
+            Self::Fixed(line_ending) => *line_ending,
+        }
+    }
+
+    /// Applies the resolved line ending to `s` (see [`LineEnding::apply`]).
+    ///
+    /// With `Auto`, mixed line endings are converted to the predominant one.
+    pub fn apply(&self, s: &str) -> String {
+        self.resolve(s).apply(s)
+    }
+}
+
+impl From<LineEnding> for LineEndingPolicy {
+    fn from(line_ending: LineEnding) -> Self {
+        Self::Fixed(line_ending)
+    }
+}
+
+impl FromStr for LineEndingPolicy {
+    type Err = ParseLineEndingError;
+
+    /// Parses `"auto"` (case-insensitive) as `Auto`, and anything accepted by
+    /// `LineEnding`'s `FromStr` implementation as `Fixed`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("auto") {
+            Ok(Self::Auto)
+        } else {
+            s.parse().map(Self::Fixed)
+        }
+    }
+}
+
+impl fmt::Display for LineEndingPolicy {
+    /// Writes `auto`, or the canonical name of the fixed line ending.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => f.write_str("auto"),
+            Self::Fixed(line_ending) => f.write_str(line_ending.name()),
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use crate::line_ending::find_line_ending;
+use crate::LineEnding;
+use std::ops::Range;
+
+/// The unit in which columns are measured by [`LineIndex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnUnit {
+    /// Columns count UTF-8 bytes.
+    Byte,
+    /// Columns count Unicode scalar values (`char`s).
+    Char,
+    /// Columns count UTF-16 code units, as used by the Language Server Protocol.
+    Utf16,
+}
+
+/// A zero-based line and column position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LineCol {
+    /// Zero-based line number.
+    pub line: usize,
+    /// Zero-based column, in the [`ColumnUnit`] it was requested in.
+    pub column: usize,
+}
+
+/// An index of line starts in a text, for fast offset ↔ (line, column) lookups.
+///
+/// The index is built in a single scan and recognizes LF, CRLF, and CR line
+/// endings, even when mixed. A CRLF counts as a single line break.
+///
+/// A text always has at least one line; a trailing line ending starts a final
+/// empty line.
+///
+/// # Example
+///
+/// ```
+/// use line_ending::{ColumnUnit, LineCol, LineEnding, LineIndex};
+///
+/// let text = "first\r\nsecond\nthird";
+/// let index = LineIndex::new(text);
+///
+/// assert_eq!(index.line_count(), 3);
+/// assert_eq!(index.line_of(9), Some(1));
+/// assert_eq!(
+///     index.line_col(9, ColumnUnit::Byte),
+///     Some(LineCol { line: 1, column: 2 })
+/// );
+/// assert_eq!(index.line_range(1), Some(7..13));
+/// assert_eq!(index.line_ending(0), Some(LineEnding::CRLF));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LineIndex<'a> {
+    text: &'a str,
+    /// Byte offset of the start of each line.
+    line_starts: Vec<usize>,
+    /// The line ending terminating each line (`None` for the final line).
+    line_endings: Vec<Option<LineEnding>>,
+}
+
+impl<'a> LineIndex<'a> {
+    /// Builds an index over `text` in a single scan.
+    pub fn new(text: &'a str) -> Self {
+        let bytes = text.as_bytes();
+        let mut line_starts = vec![0];
+        let mut line_endings = Vec::new();
+        let mut start = 0;
+
+        while let Some((pos, line_ending)) = find_line_ending(&bytes[start..]) {
+            start += pos + line_ending.as_str().len();
+            line_starts.push(start);
+            line_endings.push(Some(line_ending));
+        }
+        line_endings.push(None);
+
+        Self {
+            text,
+            line_starts,
+            line_endings,
+        }
+    }
+
+    /// Returns the indexed text.
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Returns the number of lines in the text.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Returns the zero-based line containing the byte `offset`.
+    ///
+    /// Offsets inside a line ending belong to the line it terminates. The
+    /// end-of-text offset belongs to the last line. Returns `None` if `offset`
+    /// is past the end of the text.
+    pub fn line_of(&self, offset: usize) -> Option<usize> {
+        if offset > self.text.len() {
+            return None;
+        }
+
+        Some(self.line_starts.partition_point(|&start| start <= offset) - 1)
+    }
+
+    /// Converts a byte `offset` into a line and column, measuring the column
+    /// in the given `unit`.
+    ///
+    /// Returns `None` if `offset` is past the end of the text or, for
+    /// [`ColumnUnit::Char`] and [`ColumnUnit::Utf16`], if it is not on a
+    /// character boundary.
+    pub fn line_col(&self, offset: usize, unit: ColumnUnit) -> Option<LineCol> {
+        let line = self.line_of(offset)?;
+        let start = self.line_starts[line];
+
+        let column = match unit {
+            ColumnUnit::Byte => offset - start,
+            ColumnUnit::Char => self.text.get(start..offset)?.chars().count(),
+            ColumnUnit::Utf16 => self
+                .text
+                .get(start..offset)?
+                .chars()
+                .map(char::len_utf16)
+                .sum(),
+        };
+
+        Some(LineCol { line, column })
+    }
+
+    /// Converts a line and column, measured in the given `unit`, into a byte
+    /// offset.
+    ///
+    /// Returns `None` if the line does not exist or the column lies beyond the
+    /// end of the line's content (the line ending is not addressable). For
+    /// [`ColumnUnit::Utf16`], a column pointing into the middle of a surrogate
+    /// pair also returns `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{ColumnUnit, LineCol, LineIndex};
+    ///
+    /// let index = LineIndex::new("a\r\n😀b");
+    ///
+    /// // `b` is at char column 1, but UTF-16 column 2
+    /// assert_eq!(index.offset(LineCol { line: 1, column: 1 }, ColumnUnit::Char), Some(7));
+    /// assert_eq!(index.offset(LineCol { line: 1, column: 2 }, ColumnUnit::Utf16), Some(7));
+    ///
+    /// // UTF-16 column 1 is inside the surrogate pair of `😀`
+    /// assert_eq!(index.offset(LineCol { line: 1, column: 1 }, ColumnUnit::Utf16), None);
+    /// ```
+    pub fn offset(&self, position: LineCol, unit: ColumnUnit) -> Option<usize> {
+        let range = self.line_range(position.line)?;
+        let content = &self.text[range.clone()];
+
+        let relative = match unit {
+            ColumnUnit::Byte => {
+                if position.column > content.len() {
+                    return None;
+                }
+                position.column
+            }
+            ColumnUnit::Char => content
+                .char_indices()
+                .map(|(i, _)| i)
+                .chain(std::iter::once(content.len()))
+                .nth(position.column)?,
+            ColumnUnit::Utf16 => {
+                let mut units = 0;
+                let mut found = None;
+                for (i, ch) in content
+                    .char_indices()
+                    .chain(std::iter::once((content.len(), '\0')))
+                {
+                    if units == position.column {
+                        found = Some(i);
+                        break;
+                    }
+                    if units > position.column {
+                        break;
+                    }
+                    units += ch.len_utf16();
+                }
+                found?
+            }
+        };
+
+        Some(range.start + relative)
+    }
+
+    /// Returns the byte offset of the start of `line`.
+    pub fn line_start(&self, line: usize) -> Option<usize> {
+        self.line_starts.get(line).copied()
+    }
+
+    /// Returns the byte range of `line`'s content, excluding its line ending.
+    pub fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        let range = self.line_range_with_ending(line)?;
+        let ending_len = self.line_endings[line].map_or(0, |le| le.as_str().len());
+
+        Some(range.start..range.end - ending_len)
+    }
+
+    /// Returns the byte range of `line`, including its line ending.
+    pub fn line_range_with_ending(&self, line: usize) -> Option<Range<usize>> {
+        let start = *self.line_starts.get(line)?;
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .copied()
+            .unwrap_or(self.text.len());
+
+        Some(start..end)
+    }
+
+    /// Returns the content of `line`, excluding its line ending.
+    pub fn line(&self, line: usize) -> Option<&'a str> {
+        self.line_range(line).map(|range| &self.text[range])
+    }
+
+    /// Returns the content of each line in `lines`, excluding line endings, as
+    /// slices borrowed from the indexed text.
+    ///
+    /// Returns `None` if the range extends past the last line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineIndex;
+    ///
+    /// let index = LineIndex::new("zero\r\none\ntwo\rthree");
+    /// assert_eq!(index.lines_in(1..3), Some(vec!["one", "two"]));
+    /// assert_eq!(index.lines_in(3..5), None);
+    /// ```
+    pub fn lines_in(&self, lines: Range<usize>) -> Option<Vec<&'a str>> {
+        if lines.start > lines.end || lines.end > self.line_count() {
+            return None;
+        }
+
+        lines.map(|line| self.line(line)).collect()
+    }
+
+    /// Returns each line in `lines`, including its line ending, as slices
+    /// borrowed from the indexed text.
+    ///
+    /// Returns `None` if the range extends past the last line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineIndex;
+    ///
+    /// let index = LineIndex::new("zero\r\none\ntwo\rthree");
+    /// assert_eq!(index.lines_with_endings_in(0..2), Some(vec!["zero\r\n", "one\n"]));
+    /// ```
+    pub fn lines_with_endings_in(&self, lines: Range<usize>) -> Option<Vec<&'a str>> {
+        if lines.start > lines.end || lines.end > self.line_count() {
+            return None;
+        }
+
+        lines
+            .map(|line| {
+                self.line_range_with_ending(line)
+                    .map(|range| &self.text[range])
+            })
+            .collect()
+    }
+
+    /// Returns the line ending terminating `line`.
+    ///
+    /// Returns `None` for the final line (which has no line ending) and for
+    /// lines that do not exist.
+    pub fn line_ending(&self, line: usize) -> Option<LineEnding> {
+        self.line_endings.get(line).copied().flatten()
+    }
+}
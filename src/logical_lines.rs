@@ -0,0 +1,140 @@
+use crate::line_ending::find_line_ending;
+use crate::LineEnding;
+use std::borrow::Cow;
+use std::ops::Range;
+
+/// An iterator over the logical lines of a string, where a physical line
+/// ending in a backslash immediately followed by a line ending (LF, CRLF, or
+/// CR) continues onto the next physical line.
+///
+/// Created by [`StrLineEndingExt::logical_lines`](crate::StrLineEndingExt::logical_lines).
+///
+/// Like [`StrLineEndingExt::lines_any_ending`](crate::StrLineEndingExt::lines_any_ending),
+/// a trailing line ending does not produce a final empty line. Backslash parity
+/// is not considered: the backslash in `\\` followed by a line ending still
+/// continues the line, as in the C preprocessor.
+#[derive(Debug, Clone)]
+pub struct LogicalLines<'a> {
+    text: &'a str,
+    position: usize,
+    line: usize,
+}
+
+impl<'a> LogicalLines<'a> {
+    pub(crate) fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            position: 0,
+            line: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for LogicalLines<'a> {
+    type Item = LogicalLine<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.text.as_bytes();
+        if self.position >= bytes.len() {
+            return None;
+        }
+
+        let start = self.position;
+        let mut scan = start;
+        let mut physical_lines = 0;
+
+        let (end, line_ending) = loop {
+            match find_line_ending(&bytes[scan..]) {
+                Some((pos, line_ending)) => {
+                    let at = scan + pos;
+                    physical_lines += 1;
+                    scan = at + line_ending.as_str().len();
+
+                    if at == start || bytes[at - 1] != b'\\' {
+                        break (at, Some(line_ending));
+                    }
+                    if scan == bytes.len() {
+                        // A continuation at the very end of the text
+                        break (scan, None);
+                    }
+                }
+                None => {
+                    physical_lines += 1;
+                    scan = bytes.len();
+                    break (scan, None);
+                }
+            }
+        };
+
+        let lines = self.line..self.line + physical_lines;
+        self.line += physical_lines;
+        self.position = scan;
+
+        Some(LogicalLine {
+            text: &self.text[start..end],
+            start,
+            line_ending,
+            lines,
+        })
+    }
+}
+
+/// A logical line yielded by [`LogicalLines`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogicalLine<'a> {
+    text: &'a str,
+    start: usize,
+    line_ending: Option<LineEnding>,
+    lines: Range<usize>,
+}
+
+impl<'a> LogicalLine<'a> {
+    /// Returns the original text of the logical line, including any
+    /// backslash continuations but excluding its final line ending.
+    ///
+    /// A continuation at the very end of the text has no line to continue
+    /// onto, so its backslash and line ending stay in `raw` and
+    /// [`LogicalLine::line_ending`] is `None`. Concatenating each `raw`
+    /// followed by its line ending therefore always rebuilds the source.
+    pub fn raw(&self) -> &'a str {
+        self.text
+    }
+
+    /// Returns the logical line with each backslash continuation (the
+    /// backslash and the line ending following it) removed.
+    ///
+    /// Borrows from the source when the line has no continuations.
+    pub fn joined(&self) -> Cow<'a, str> {
+        if find_line_ending(self.text.as_bytes()).is_none() {
+            return Cow::Borrowed(self.text);
+        }
+
+        let mut joined = String::with_capacity(self.text.len());
+        let mut rest = self.text;
+
+        // Every line ending within a logical line is preceded by a backslash
+        while let Some((pos, line_ending)) = find_line_ending(rest.as_bytes()) {
+            joined.push_str(&rest[..pos - 1]);
+            rest = &rest[pos + line_ending.as_str().len()..];
+        }
+        joined.push_str(rest);
+
+        Cow::Owned(joined)
+    }
+
+    /// Returns the line ending terminating the logical line, or `None` if it
+    /// runs to the end of the text.
+    pub fn line_ending(&self) -> Option<LineEnding> {
+        self.line_ending
+    }
+
+    /// Returns the byte range of [`LogicalLine::raw`] in the source.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.start + self.text.len()
+    }
+
+    /// Returns the zero-based range of physical lines the logical line spans.
+    pub fn lines(&self) -> Range<usize> {
+        self.lines.clone()
+    }
+}
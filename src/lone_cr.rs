@@ -0,0 +1,170 @@
+use crate::LineEnding;
+use std::borrow::Cow;
+
+/// Policy for a carriage return (`\r`) that is not part of a CRLF.
+///
+/// Used with [`LineEnding::normalize_with_lone_cr`],
+/// [`LineEnding::apply_with_lone_cr`], and [`LineEnding::split_with_lone_cr`].
+/// LF and CRLF are always line endings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum LoneCr {
+    /// Treat a lone CR as a line ending (classic Mac OS), as
+    /// [`LineEnding::normalize`] and [`LineEnding::apply`] do.
+    #[default]
+    Terminator,
+    /// Treat a lone CR as part of the line's content, leaving it unchanged.
+    Content,
+    /// Treat a lone CR as returning to the start of the line to rewrite it,
+    /// keeping only the final rewrite: the text after the line's last lone CR.
+    ///
+    /// Progress bars and spinners that redraw a line collapse into their final
+    /// state. A lone CR with nothing after it (before the line ending or the
+    /// end of the text) is not a rewrite, so the text before it is kept.
+    Overwrite,
+    /// Emulate a terminal: a lone CR returns to the start of the line and the
+    /// following text overwrites it, character by character.
+    ///
+    /// Unlike `Overwrite`, a rewrite shorter than the text it overwrites leaves
+    /// the end of that text in place, as it would appear on screen.
+    Terminal,
+}
+
+/// Returns the final rewrite of `line`, in which each `\r` starts a rewrite.
+fn final_rewrite(line: &str) -> &str {
+    line.trim_end_matches('\r')
+        .rsplit('\r')
+        .next()
+        .unwrap_or_default()
+}
+
+/// Returns the text a terminal would show after writing `line`, in which each
+/// `\r` moves the cursor back to the start of the line.
+fn terminal(line: &str) -> Cow<'_, str> {
+    if !line.contains('\r') {
+        return Cow::Borrowed(line);
+    }
+
+    let mut screen: Vec<char> = Vec::new();
+    for rewrite in line.split('\r') {
+        for (column, ch) in rewrite.chars().enumerate() {
+            match screen.get_mut(column) {
+                Some(cell) => *cell = ch,
+                None => screen.push(ch),
+            }
+        }
+    }
+
+    Cow::Owned(screen.into_iter().collect())
+}
+
+impl LineEnding {
+    /// Converts all line endings in a string to LF (`\n`), like
+    /// [`LineEnding::normalize`], handling lone CRs according to `policy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, LoneCr};
+    ///
+    /// let log = "Downloading  10%\rDownloading 100%\r\nDone\n";
+    ///
+    /// assert_eq!(
+    ///     LineEnding::normalize_with_lone_cr(log, LoneCr::Terminator),
+    ///     "Downloading  10%\nDownloading 100%\nDone\n"
+    /// );
+    /// assert_eq!(
+    ///     LineEnding::normalize_with_lone_cr(log, LoneCr::Content),
+    ///     "Downloading  10%\rDownloading 100%\nDone\n"
+    /// );
+    /// assert_eq!(
+    ///     LineEnding::normalize_with_lone_cr(log, LoneCr::Overwrite),
+    ///     "Downloading 100%\nDone\n"
+    /// );
+    ///
+    /// // Each rewrite is as long as the last, so a terminal shows the same
+    /// assert_eq!(
+    ///     LineEnding::normalize_with_lone_cr(log, LoneCr::Terminal),
+    ///     "Downloading 100%\nDone\n"
+    /// );
+    /// ```
+    pub fn normalize_with_lone_cr(s: &str, policy: LoneCr) -> String {
+        Self::LF.apply_with_lone_cr(s, policy)
+    }
+
+    /// Applies this line ending type to `s`, like [`LineEnding::apply`],
+    /// handling lone CRs according to `policy`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, LoneCr};
+    ///
+    /// assert_eq!(
+    ///     LineEnding::CRLF.apply_with_lone_cr("a\rb\nc", LoneCr::Content),
+    ///     "a\rb\r\nc"
+    /// );
+    /// ```
+    pub fn apply_with_lone_cr(&self, s: &str, policy: LoneCr) -> String {
+        if policy == LoneCr::Terminator {
+            return self.apply(s);
+        }
+
+        let mut result = String::with_capacity(s.len());
+        let mut rest = s;
+
+        loop {
+            // Only LF and CRLF end a line; a lone CR stays in its line's content
+            let (line, next) = match rest.find('\n') {
+                Some(pos) => (
+                    rest[..pos].strip_suffix('\r').unwrap_or(&rest[..pos]),
+                    Some(pos + 1),
+                ),
+                None => (rest, None),
+            };
+
+            match policy {
+                LoneCr::Overwrite => result.push_str(final_rewrite(line)),
+                LoneCr::Terminal => result.push_str(&terminal(line)),
+                LoneCr::Terminator | LoneCr::Content => result.push_str(line),
+            }
+
+            match next {
+                Some(next) => {
+                    result.push_str(self.as_str());
+                    rest = &rest[next..];
+                }
+                None => break,
+            }
+        }
+
+        result
+    }
+
+    /// Splits a string into lines on LF and CRLF, handling lone CRs according
+    /// to `policy`.
+    ///
+    /// Like [`LineEnding::split`], a trailing line ending produces a final
+    /// empty line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, LoneCr};
+    ///
+    /// let output = "[    ]\r[=>  ]\r[====]\nok";
+    ///
+    /// assert_eq!(
+    ///     LineEnding::split_with_lone_cr(output, LoneCr::Overwrite),
+    ///     vec!["[====]", "ok"]
+    /// );
+    /// assert_eq!(LineEnding::split_with_lone_cr(output, LoneCr::Terminator).len(), 4);
+    /// ```
+    pub fn split_with_lone_cr(s: &str, policy: LoneCr) -> Vec<String> {
+        Self::normalize_with_lone_cr(s, policy)
+            .split('\n')
+            .map(String::from)
+            .collect()
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{LineEnding, LineEndingScores};
+use std::collections::HashMap;
+
+/// A malformed two-character line ending produced by legacy systems or buggy
+/// tools, which would otherwise be read as two separate line endings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::upper_case_acronyms)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum MalformedLineEnding {
+    /// Line Feed + Carriage Return (`\n\r`), a reversed CRLF.
+    LFCR,
+    /// Carriage Return + CRLF (`\r\r\n`), typically from converting CRLF text
+    /// to CRLF a second time.
+    CRCRLF,
+}
+
+impl MalformedLineEnding {
+    /// All malformed line ending types.
+    pub const ALL: [MalformedLineEnding; 2] = [Self::LFCR, Self::CRCRLF];
+
+    /// Returns the string representation of the sequence (`\n\r` or `\r\r\n`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::MalformedLineEnding;
+    ///
+    /// assert_eq!(MalformedLineEnding::LFCR.as_str(), "\n\r");
+    /// assert_eq!(MalformedLineEnding::CRCRLF.as_str(), "\r\r\n");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LFCR => "\n\r",
+            Self::CRCRLF => "\r\r\n",
+        }
+    }
+}
+
+/// A mapping of malformed line ending types to their occurrence counts.
+pub type MalformedLineEndingScores = HashMap<MalformedLineEnding, usize>;
+
+/// Counts of well-formed and malformed line endings in a text.
+///
+/// Returned by [`LineEnding::report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LineEndingReport {
+    /// Number of line endings of each type, excluding those that are part of
+    /// a malformed sequence.
+    pub well_formed: LineEndingScores,
+    /// Number of malformed sequences of each type.
+    pub malformed: MalformedLineEndingScores,
+}
+
+impl LineEndingReport {
+    /// Returns `true` if the text contains any malformed line endings.
+    pub fn has_malformed(&self) -> bool {
+        self.malformed.values().any(|&count| count > 0)
+    }
+}
+
+/// A line ending recognized while scanning for malformed sequences.
+enum Terminator {
+    WellFormed(LineEnding),
+    Malformed(MalformedLineEnding),
+}
+
+impl Terminator {
+    fn len(&self) -> usize {
+        match self {
+            Self::WellFormed(line_ending) => line_ending.as_str().len(),
+            Self::Malformed(malformed) => malformed.as_str().len(),
+        }
+    }
+}
+
+/// How the ambiguous sequences `\n\r\n` and `\r\r\n` are read, decided
+/// once from the whole text.
+#[derive(Debug, Clone, Copy)]
+struct Context {
+    /// Read `\n\r\n` as an LFCR followed by an LF instead of an LF followed
+    /// by a CRLF.
+    lfcr_before_lf: bool,
+    /// Read `\r\r\n` as a CR followed by a CRLF instead of a CRCRLF.
+    cr_before_crlf: bool,
+}
+
+impl Context {
+    /// Looks for unambiguous line endings: LFCRs not followed by an LF, CRLFs
+    /// not preceded by an LF or CR, and CRs that neither start a `\r\n` or
+    /// `\r\r\n` nor follow an LF.
+    ///
+    /// Text with LFCRs but no such CRLFs is LFCR text, where `\n\r\n` is a
+    /// blank line. Text with lone CRs but no such CRLFs is CR text, where
+    /// `\r\r\n` is a CR line followed by a CRLF.
+    fn of(bytes: &[u8]) -> Self {
+        let (mut lfcr, mut crlf, mut cr) = (false, false, false);
+
+        for (i, &b) in bytes.iter().enumerate() {
+            let previous = i.checked_sub(1).map(|j| bytes[j]);
+            let next = bytes.get(i + 1).copied();
+            let after_next = bytes.get(i + 2).copied();
+
+            match (b, next) {
+                (b'\n', Some(b'\r')) if after_next != Some(b'\n') => lfcr = true,
+                (b'\r', Some(b'\n')) if !matches!(previous, Some(b'\n' | b'\r')) => crlf = true,
+                (b'\r', Some(b'\r')) if after_next == Some(b'\n') => {}
+                (b'\r', _) if next != Some(b'\n') && previous != Some(b'\n') => cr = true,
+                _ => {}
+            }
+        }
+
+        Self {
+            lfcr_before_lf: lfcr && !crlf,
+            cr_before_crlf: cr && !crlf,
+        }
+    }
+}
+
+/// Finds the first line ending in `bytes`, recognizing malformed sequences as
+/// single terminators, and returns its byte offset.
+///
+/// `\r\n\r\n` is always two CRLFs; `\n\r\n` and `\r\r\n` are read
+/// according to `context`.
+fn find_terminator(bytes: &[u8], context: Context) -> Option<(usize, Terminator)> {
+    let pos = bytes.iter().position(|&b| b == b'\r' || b == b'\n')?;
+
+    let terminator = match &bytes[pos..] {
+        [b'\r', b'\r', b'\n', ..] if !context.cr_before_crlf => {
+            Terminator::Malformed(MalformedLineEnding::CRCRLF)
+        }
+        [b'\r', b'\n', ..] => Terminator::WellFormed(LineEnding::CRLF),
+        [b'\r', ..] => Terminator::WellFormed(LineEnding::CR),
+        [b'\n', b'\r', b'\n', ..] if !context.lfcr_before_lf => {
+            Terminator::WellFormed(LineEnding::LF)
+        }
+        [b'\n', b'\r', ..] => Terminator::Malformed(MalformedLineEnding::LFCR),
+        _ => Terminator::WellFormed(LineEnding::LF),
+    };
+
+    Some((pos, terminator))
+}
+
+impl LineEnding {
+    /// Counts the line endings in `s`, recognizing LFCR (`\n\r`) and
+    /// `\r\r\n` as single malformed terminators.
+    ///
+    /// [`LineEnding::score_mixed_types`] counts these sequences as an LF and a
+    /// CR, or a CR and a CRLF, which makes a text look mixed and makes
+    /// normalization produce unexpected blank lines.
+    ///
+    /// Some sequences are ambiguous, so they are read from the rest of the
+    /// text:
+    ///
+    /// - `\n\r\n` is a blank line (LFCR + LF) in text that has other LFCRs
+    ///   and no CRLFs, and an LF followed by a CRLF otherwise.
+    /// - `\r\r\n` is a CR followed by a CRLF in text that has lone CRs and no
+    ///   other CRLFs, and a CRCRLF otherwise.
+    ///
+    /// Either reading can be wrong: mixed LF, CR, and CRLF text may still be
+    /// reported as malformed, and a malformed sequence in otherwise mixed text
+    /// may be read as well-formed line endings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, MalformedLineEnding};
+    ///
+    /// let report = LineEnding::report("first\n\rsecond\n\rthird\r\r\nfourth\n");
+    ///
+    /// assert!(report.has_malformed());
+    /// assert_eq!(report.malformed[&MalformedLineEnding::LFCR], 2);
+    /// assert_eq!(report.malformed[&MalformedLineEnding::CRCRLF], 1);
+    /// assert_eq!(report.well_formed[&LineEnding::LF], 1);
+    /// assert_eq!(report.well_formed[&LineEnding::CR], 0);
+    /// ```
+    pub fn report(s: &str) -> LineEndingReport {
+        let mut well_formed: LineEndingScores = Self::ALL.iter().map(|&le| (le, 0)).collect();
+        let mut malformed: MalformedLineEndingScores = MalformedLineEnding::ALL
+            .iter()
+            .map(|&malformed| (malformed, 0))
+            .collect();
+
+        let context = Context::of(s.as_bytes());
+        let mut rest = s.as_bytes();
+        while let Some((pos, terminator)) = find_terminator(rest, context) {
+            rest = &rest[pos + terminator.len()..];
+            match terminator {
+                Terminator::WellFormed(line_ending) => {
+                    *well_formed.entry(line_ending).or_default() += 1
+                }
+                Terminator::Malformed(malformed_ending) => {
+                    *malformed.entry(malformed_ending).or_default() += 1
+                }
+            }
+        }
+
+        LineEndingReport {
+            well_formed,
+            malformed,
+        }
+    }
+
+    /// Applies this line ending type to `s` like [`LineEnding::apply`], but
+    /// first collapses each malformed sequence (`\n\r` or `\r\r\n`) into a
+    /// single line ending.
+    ///
+    /// Ambiguous sequences are read as described in [`LineEnding::report`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let text = "first\n\rsecond\r\r\nthird\r\n";
+    ///
+    /// assert_eq!(LineEnding::LF.apply(text), "first\n\nsecond\n\nthird\n");
+    /// assert_eq!(
+    ///     LineEnding::LF.apply_repairing_malformed(text),
+    ///     "first\nsecond\nthird\n"
+    /// );
+    /// ```
+    pub fn apply_repairing_malformed(&self, s: &str) -> String {
+        let mut result = String::with_capacity(s.len());
+        let context = Context::of(s.as_bytes());
+        let mut rest = s;
+
+        while let Some((pos, terminator)) = find_terminator(rest.as_bytes(), context) {
+            result.push_str(&rest[..pos]);
+            result.push_str(self.as_str());
+            rest = &rest[pos + terminator.len()..];
+        }
+        result.push_str(rest);
+
+        result
+    }
+}
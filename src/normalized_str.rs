@@ -0,0 +1,177 @@
+use crate::line_ending::split_first_line;
+use crate::LineEnding;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// An iterator over the bytes of a string as if it had been normalized with
+/// [`LineEnding::normalize`], without allocating.
+#[derive(Debug, Clone)]
+struct NormalizedBytes<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> NormalizedBytes<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            bytes: s.as_bytes(),
+            position: 0,
+        }
+    }
+}
+
+impl Iterator for NormalizedBytes<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let byte = *self.bytes.get(self.position)?;
+        self.position += 1;
+
+        match byte {
+            b'\r' => {
+                if self.bytes.get(self.position) == Some(&b'\n') {
+                    self.position += 1;
+                }
+                Some(b'\n')
+            }
+            _ => Some(byte),
+        }
+    }
+}
+
+impl LineEnding {
+    /// Compares two strings for equality, treating all line endings (LF, CRLF,
+    /// and CR) as equal. Does not allocate.
+    ///
+    /// Equivalent to `LineEnding::normalize(a) == LineEnding::normalize(b)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// assert!(LineEnding::eq_ignore_line_endings("a\r\nb\r\n", "a\nb\n"));
+    /// assert!(LineEnding::eq_ignore_line_endings("a\rb", "a\nb"));
+    /// assert!(!LineEnding::eq_ignore_line_endings("a\r\nb", "a\n\nb"));
+    /// ```
+    pub fn eq_ignore_line_endings(a: &str, b: &str) -> bool {
+        NormalizedBytes::new(a).eq(NormalizedBytes::new(b))
+    }
+
+    /// Feeds a string into `state` as if it had been normalized with
+    /// [`LineEnding::normalize`], so strings that differ only in line endings
+    /// hash identically. Does not allocate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    /// use line_ending::LineEnding;
+    ///
+    /// fn hash(s: &str) -> u64 {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     LineEnding::hash_ignoring_line_endings(s, &mut hasher);
+    ///     hasher.finish()
+    /// }
+    ///
+    /// assert_eq!(hash("a\r\nb"), hash("a\nb"));
+    /// ```
+    pub fn hash_ignoring_line_endings<H: Hasher>(s: &str, state: &mut H) {
+        let mut remaining = s.as_bytes();
+
+        // Feed line contents and normalized line endings in chunks that depend
+        // only on the normalized text, so the result is independent of the
+        // original line endings
+        while !remaining.is_empty() {
+            let (end, next_start) = split_first_line(remaining);
+            state.write(&remaining[..end]);
+            if next_start > end {
+                state.write(b"\n");
+            }
+            remaining = &remaining[next_start..];
+        }
+
+        // Terminate like `str`'s `Hash` implementation, so that concatenated
+        // strings do not collide
+        state.write_u8(0xff);
+    }
+}
+
+/// A string wrapper whose equality, ordering, and hashing ignore line ending
+/// differences (LF, CRLF, and CR all compare equal).
+///
+/// Useful as a `HashMap`/`BTreeMap` key, or for comparing expected and actual
+/// text in tests that run on both Unix and Windows checkouts.
+///
+/// Ordering is that of the normalized text, i.e. every line ending sorts as `\n`.
+///
+/// # Example
+///
+/// ```
+/// use line_ending::NormalizedStr;
+///
+/// assert_eq!(NormalizedStr::new("a\r\nb"), NormalizedStr::new("a\nb"));
+/// assert_ne!(NormalizedStr::new("a\r\nb"), NormalizedStr::new("a\n\nb"));
+///
+/// let owned = NormalizedStr::new(String::from("a\rb"));
+/// assert_eq!(owned.as_str(), "a\rb"); // The original text is kept as-is
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NormalizedStr<S>(S);
+
+impl<S: AsRef<str>> NormalizedStr<S> {
+    /// Wraps a string.
+    pub fn new(s: S) -> Self {
+        Self(s)
+    }
+
+    /// Returns the wrapped string, with its original line endings.
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    /// Unwraps the string.
+    pub fn into_inner(self) -> S {
+        self.0
+    }
+}
+
+impl<S: AsRef<str>> From<S> for NormalizedStr<S> {
+    fn from(s: S) -> Self {
+        Self(s)
+    }
+}
+
+impl<S: AsRef<str>, T: AsRef<str>> PartialEq<NormalizedStr<T>> for NormalizedStr<S> {
+    fn eq(&self, other: &NormalizedStr<T>) -> bool {
+        LineEnding::eq_ignore_line_endings(self.as_str(), other.as_str())
+    }
+}
+
+impl<S: AsRef<str>> Eq for NormalizedStr<S> {}
+
+impl<S: AsRef<str>, T: AsRef<str>> PartialOrd<NormalizedStr<T>> for NormalizedStr<S> {
+    fn partial_cmp(&self, other: &NormalizedStr<T>) -> Option<Ordering> {
+        Some(NormalizedBytes::new(self.as_str()).cmp(NormalizedBytes::new(other.as_str())))
+    }
+}
+
+impl<S: AsRef<str>> Ord for NormalizedStr<S> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        NormalizedBytes::new(self.as_str()).cmp(NormalizedBytes::new(other.as_str()))
+    }
+}
+
+impl<S: AsRef<str>> Hash for NormalizedStr<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        LineEnding::hash_ignoring_line_endings(self.as_str(), state);
+    }
+}
+
+impl<S: AsRef<str>> fmt::Display for NormalizedStr<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,161 @@
+use crate::line_ending::find_line_ending;
+use crate::LineEnding;
+
+/// A bidirectional mapping between byte offsets in an original text and the
+/// text produced by converting its line endings.
+///
+/// Offsets within line content map one-to-one. Offsets that fall inside a
+/// multi-byte line ending (e.g. between the `\r` and `\n` of a CRLF) map to the
+/// start of the corresponding line ending in the other text.
+///
+/// Returned by [`LineEnding::apply_with_offset_map`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetMap {
+    /// One entry per line, sorted by both offsets.
+    lines: Vec<LineSpan>,
+    original_len: usize,
+    converted_len: usize,
+}
+
+/// The start of a line in both texts, plus the length of its content
+/// (excluding the line ending), which is identical in both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct LineSpan {
+    original_start: usize,
+    converted_start: usize,
+    content_len: usize,
+}
+
+impl OffsetMap {
+    /// Returns the length, in bytes, of the original text.
+    pub fn original_len(&self) -> usize {
+        self.original_len
+    }
+
+    /// Returns the length, in bytes, of the converted text.
+    pub fn converted_len(&self) -> usize {
+        self.converted_len
+    }
+
+    /// Maps a byte offset in the original text to the converted text.
+    ///
+    /// Returns `None` if `offset` is past the end of the original text. The
+    /// end-of-text offset itself is valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let (_, map) = LineEnding::LF.apply_with_offset_map("ab\r\ncd");
+    ///
+    /// assert_eq!(map.to_converted(1), Some(1)); // `b`
+    /// assert_eq!(map.to_converted(4), Some(3)); // `c`
+    /// assert_eq!(map.to_converted(3), Some(2)); // Inside CRLF → start of LF
+    /// assert_eq!(map.to_converted(6), Some(5)); // End of text
+    /// assert_eq!(map.to_converted(7), None);
+    /// ```
+    pub fn to_converted(&self, offset: usize) -> Option<usize> {
+        if offset > self.original_len {
+            return None;
+        }
+
+        let index = self
+            .lines
+            .partition_point(|line| line.original_start <= offset)
+            - 1;
+        let line = self.lines[index];
+        let delta = (offset - line.original_start).min(line.content_len);
+
+        Some(line.converted_start + delta)
+    }
+
+    /// Maps a byte offset in the converted text back to the original text.
+    ///
+    /// Returns `None` if `offset` is past the end of the converted text. The
+    /// end-of-text offset itself is valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let (_, map) = LineEnding::CRLF.apply_with_offset_map("ab\ncd");
+    ///
+    /// assert_eq!(map.to_original(5), Some(4)); // `d`
+    /// assert_eq!(map.to_original(3), Some(2)); // Inside CRLF → start of LF
+    /// assert_eq!(map.to_original(6), Some(5)); // End of text
+    /// ```
+    pub fn to_original(&self, offset: usize) -> Option<usize> {
+        if offset > self.converted_len {
+            return None;
+        }
+
+        let index = self
+            .lines
+            .partition_point(|line| line.converted_start <= offset)
+            - 1;
+        let line = self.lines[index];
+        let delta = (offset - line.converted_start).min(line.content_len);
+
+        Some(line.original_start + delta)
+    }
+}
+
+impl LineEnding {
+    /// Applies a specific line ending type to an existing string, returning the
+    /// converted text together with an [`OffsetMap`] for translating byte
+    /// offsets between the original and converted text.
+    ///
+    /// The converted text is identical to that of [`LineEnding::apply`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let original = "let a = 1;\r\nlet b = 2;\r\n";
+    /// let (converted, map) = LineEnding::LF.apply_with_offset_map(original);
+    ///
+    /// let b_original = original.find('b').unwrap();
+    /// let b_converted = map.to_converted(b_original).unwrap();
+    ///
+    /// assert_eq!(&converted[b_converted..b_converted + 1], "b");
+    /// assert_eq!(map.to_original(b_converted), Some(b_original));
+    /// ```
+    pub fn apply_with_offset_map(&self, s: &str) -> (String, OffsetMap) {
+        let target = self.as_str();
+        let mut converted = String::with_capacity(s.len());
+        let mut lines = Vec::new();
+        let mut original_start = 0;
+
+        loop {
+            let rest = &s[original_start..];
+            let found = find_line_ending(rest.as_bytes());
+            let content_len = found.map_or(rest.len(), |(pos, _)| pos);
+
+            lines.push(LineSpan {
+                original_start,
+                converted_start: converted.len(),
+                content_len,
+            });
+            converted.push_str(&rest[..content_len]);
+
+            match found {
+                Some((_, line_ending)) => {
+                    converted.push_str(target);
+                    original_start += content_len + line_ending.as_str().len();
+                }
+                None => break,
+            }
+        }
+
+        let map = OffsetMap {
+            lines,
+            original_len: s.len(),
+            converted_len: converted.len(),
+        };
+
+        (converted, map)
+    }
+}
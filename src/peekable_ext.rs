@@ -1,37 +1,208 @@
 use crate::LineEnding;
+use std::io;
 use std::iter::Peekable;
+use std::ops::Range;
 
-/// A trait to extend `Peekable<char>` with a method for consuming line endings.
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A unit of text in which line endings can be recognized: a `char`, a UTF-8
+/// byte (`u8`), a UTF-16 code unit (`u16`), a byte read from an
+/// [`io::Bytes`] stream (`io::Result<u8>`), or an indexed `char` from
+/// [`str::char_indices`] (`(usize, char)`).
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+pub trait LineEndingUnit: sealed::Sealed {
+    /// Returns `true` if this unit is a carriage return (`\r`).
+    fn is_cr(&self) -> bool;
+
+    /// Returns `true` if this unit is a line feed (`\n`).
+    fn is_lf(&self) -> bool;
+
+    /// Returns `true` if this unit is an error rather than text, such as an
+    /// `Err` from an [`io::Bytes`] stream.
+    ///
+    /// Errors are neither content nor line endings: the line reading methods
+    /// of [`PeekableLineEndingExt`] stop at them and leave them in the
+    /// iterator.
+    fn is_error(&self) -> bool {
+        false
+    }
+}
+
+impl sealed::Sealed for char {}
+
+impl LineEndingUnit for char {
+    fn is_cr(&self) -> bool {
+        *self == '\r'
+    }
+
+    fn is_lf(&self) -> bool {
+        *self == '\n'
+    }
+}
+
+impl sealed::Sealed for u8 {}
+
+impl LineEndingUnit for u8 {
+    fn is_cr(&self) -> bool {
+        *self == b'\r'
+    }
+
+    fn is_lf(&self) -> bool {
+        *self == b'\n'
+    }
+}
+
+impl sealed::Sealed for u16 {}
+
+impl LineEndingUnit for u16 {
+    fn is_cr(&self) -> bool {
+        *self == u16::from(b'\r')
+    }
+
+    fn is_lf(&self) -> bool {
+        *self == u16::from(b'\n')
+    }
+}
+
+impl sealed::Sealed for io::Result<u8> {}
+
+/// Errors are never line endings; they are left in the iterator for the
+/// caller to handle.
+impl LineEndingUnit for io::Result<u8> {
+    fn is_cr(&self) -> bool {
+        matches!(self, Ok(b'\r'))
+    }
+
+    fn is_lf(&self) -> bool {
+        matches!(self, Ok(b'\n'))
+    }
+
+    fn is_error(&self) -> bool {
+        self.is_err()
+    }
+}
+
+impl sealed::Sealed for (usize, char) {}
+
+impl LineEndingUnit for (usize, char) {
+    fn is_cr(&self) -> bool {
+        self.1 == '\r'
+    }
+
+    fn is_lf(&self) -> bool {
+        self.1 == '\n'
+    }
+}
+
+/// A trait to extend `Peekable` iterators over characters, bytes, or UTF-16
+/// code units with methods for consuming line endings and lines.
 pub trait PeekableLineEndingExt {
     /// Consumes the line ending from the iterator if the upcoming characters
     /// form a line break (CR, LF, or CRLF), and returns its type.
     /// Otherwise, returns `None`.
     ///
     /// **Note:** Escaped sequences (`\\r`, `\\n`, `\\r\\n`) are treated as part of the content
-    /// and are **not consumed** as actual line endings.
+    /// and are **not consumed** as actual line endings. Only one character of
+    /// lookahead is used, so escape state is not tracked across the stream; use
+    /// [`EscapeAwareLineEndings`](crate::EscapeAwareLineEndings) when backslash
+    /// parity matters.
     fn consume_line_ending(&mut self) -> Option<LineEnding>;
+
+    /// Consumes the rest of the current line and the line ending that
+    /// terminates it, returning the line's content (collected into any
+    /// `Default + Extend` collection, such as `String` or `Vec<u8>`) and the
+    /// line ending's type.
+    ///
+    /// The line ending is `None` if the iterator ran out (or reached an error)
+    /// before one was found. Escaped sequences are kept in the content, as
+    /// with [`PeekableLineEndingExt::consume_line_ending`].
+    ///
+    /// Returns `None` if the iterator is exhausted, or if its next unit is an
+    /// error (see [`LineEndingUnit::is_error`]). Errors are never consumed, so
+    /// use `peek` to tell the two apart.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, PeekableLineEndingExt};
+    ///
+    /// let mut it = "key = a\\nb\r\nnext".chars().peekable();
+    ///
+    /// let (line, ending): (String, _) = it.take_line().unwrap();
+    /// assert_eq!(line, "key = a\\nb");
+    /// assert_eq!(ending, Some(LineEnding::CRLF));
+    ///
+    /// assert_eq!(it.take_line::<String>(), Some(("next".to_string(), None)));
+    /// assert_eq!(it.take_line::<String>(), None);
+    /// ```
+    fn take_line<C>(&mut self) -> Option<(C, Option<LineEnding>)>
+    where
+        Self: Iterator,
+        C: Default + Extend<Self::Item>;
+
+    /// Consumes consecutive line endings (i.e. blank lines) at the front of
+    /// the iterator and returns how many were consumed.
+    ///
+    /// A CRLF counts as one line ending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::PeekableLineEndingExt;
+    ///
+    /// let mut it = "\r\n\n\rtext".chars().peekable();
+    /// assert_eq!(it.skip_line_endings(), 3);
+    /// assert_eq!(it.peek(), Some(&'t'));
+    /// ```
+    fn skip_line_endings(&mut self) -> usize;
+
+    /// Discards the rest of the current line and consumes its line ending,
+    /// leaving the iterator at the start of the next line.
+    ///
+    /// Returns the type of the line ending consumed, or `None` if the iterator
+    /// ran out first. Stops without consuming it at an error (see
+    /// [`LineEndingUnit::is_error`]), also returning `None`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, PeekableLineEndingExt};
+    ///
+    /// let mut it = "# comment\rcode".chars().peekable();
+    /// assert_eq!(it.skip_to_next_line(), Some(LineEnding::CR));
+    /// assert_eq!(it.peek(), Some(&'c'));
+    /// ```
+    fn skip_to_next_line(&mut self) -> Option<LineEnding>;
 }
 
 impl<I> PeekableLineEndingExt for Peekable<I>
 where
-    I: Iterator<Item = char> + Clone,
+    I: Iterator,
+    I::Item: LineEndingUnit,
 {
     /// Consumes the line ending from the iterator if the upcoming characters form a line break,
     /// and returns its corresponding `LineEnding` variant.
     ///
     /// This method works as follows:
     /// - It peeks at the next character:
-    ///   - If the next character is `'\\'`, it checks if the following character is `'r'` or `'n'`.
-    ///     - If so, it is **an escaped sequence** (`\\r`, `\\n`, or `\\r\\n`) and is treated as **part of the content**.
-    ///     - It is **not** consumed as an actual line ending.
-    ///   - If the next character is `'\r'`, it clones the iterator and checks the following character.
-    ///     - If that character is `'\n'`, it consumes both characters (interpreting them
+    ///   - If the next character is `'\r'`, it consumes it and peeks at the following character.
+    ///     - If that character is `'\n'`, it consumes it as well (interpreting both
     ///       as a CRLF sequence) and returns `Some(LineEnding::CRLF)`.
-    ///     - If the next character is **not** `'\n'`, it consumes the lone `'\r'` and returns `Some(LineEnding::CR)`.
+    ///     - Otherwise, the lone `'\r'` has been consumed and it returns `Some(LineEnding::CR)`.
     ///   - If the next character is `'\n'`, it consumes it and returns `Some(LineEnding::LF)`.
+    ///   - Any other character, including the `'\\'` of an escaped sequence (`\\r`, `\\n`,
+    ///     or `\\r\\n`), is **not** a line ending and is left in the iterator as
+    ///     **part of the content**.
     ///
     /// If no line ending is detected at the front of the iterator, the method returns `None`.
     ///
+    /// Only `peek` is used for lookahead, so the underlying iterator does not need to
+    /// be `Clone` (e.g. `io::Bytes` or a channel receiver). The same logic applies to
+    /// `u8` and `u16` (UTF-16) code units.
+    ///
     /// # Behavior Summary:
     /// - **`\r\n`** → Consumed and returns `Some(LineEnding::CRLF)`
     /// - **`\r`** → Consumed and returns `Some(LineEnding::CR)`
@@ -65,37 +236,112 @@ where
     /// let mut it: Peekable<Chars> = "\\r\\nline1".chars().peekable();
     /// assert_eq!(it.consume_line_ending(), None); // `\\r\\n` is part of content
     /// assert_eq!(it.peek(), Some(&'\\')); // The iterator remains at '\\'
+    ///
+    /// // Example with bytes and UTF-16 code units:
+    /// let mut it = b"\r\nline1".iter().copied().peekable();
+    /// assert_eq!(it.consume_line_ending(), Some(LineEnding::CRLF));
+    ///
+    /// let utf16: Vec<u16> = "\rline1".encode_utf16().collect();
+    /// let mut it = utf16.into_iter().peekable();
+    /// assert_eq!(it.consume_line_ending(), Some(LineEnding::CR));
     /// ```
     fn consume_line_ending(&mut self) -> Option<LineEnding> {
-        if let Some(&first) = self.peek() {
-            if first == '\\' {
-                // Check if the next character is 'r' or 'n' (escaped line endings)
-                let mut clone = self.clone();
-                clone.next(); // Consume first '\\'
-
-                if let Some(&second) = clone.peek() {
-                    if second == 'r' || second == 'n' {
-                        // If escaped, do nothing and return None
-                        return None;
-                    }
-                }
-            } else if first == '\r' {
-                let mut clone = self.clone();
-                clone.next(); // Consume '\r'
-                if let Some(&second) = clone.peek() {
-                    if second == '\n' {
-                        self.next(); // Consume '\r'
-                        self.next(); // Consume '\n'
-                        return Some(LineEnding::CRLF);
-                    }
-                }
-                self.next(); // Consume '\r'
-                return Some(LineEnding::CR);
-            } else if first == '\n' {
-                self.next(); // Consume '\n'
-                return Some(LineEnding::LF);
+        if self.next_if(LineEndingUnit::is_cr).is_some() {
+            if self.next_if(LineEndingUnit::is_lf).is_some() {
+                return Some(LineEnding::CRLF);
             }
+            return Some(LineEnding::CR);
+        }
+
+        if self.next_if(LineEndingUnit::is_lf).is_some() {
+            return Some(LineEnding::LF);
         }
+
+        // Anything else, including the backslash of an escaped sequence, is content
         None
     }
+
+    fn take_line<C>(&mut self) -> Option<(C, Option<LineEnding>)>
+    where
+        C: Default + Extend<<Self as Iterator>::Item>,
+    {
+        if self.peek()?.is_error() {
+            return None;
+        }
+
+        let mut line = C::default();
+        loop {
+            if let Some(line_ending) = self.consume_line_ending() {
+                return Some((line, Some(line_ending)));
+            }
+            match self.next_if(|unit| !unit.is_error()) {
+                Some(unit) => line.extend(Some(unit)),
+                None => return Some((line, None)),
+            }
+        }
+    }
+
+    fn skip_line_endings(&mut self) -> usize {
+        let mut count = 0;
+        while self.consume_line_ending().is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    fn skip_to_next_line(&mut self) -> Option<LineEnding> {
+        loop {
+            if let Some(line_ending) = self.consume_line_ending() {
+                return Some(line_ending);
+            }
+            self.next_if(|unit| !unit.is_error())?;
+        }
+    }
+}
+
+/// A trait to extend `Peekable` iterators over indexed characters (such as
+/// [`CharIndices`](std::str::CharIndices)) with a method for consuming line
+/// endings along with their byte range in the source.
+///
+/// The methods of [`PeekableLineEndingExt`] are also available on these
+/// iterators.
+pub trait PeekableCharIndicesLineEndingExt {
+    /// Consumes the line ending at the front of the iterator, if any, and
+    /// returns its type together with its byte range in the source.
+    ///
+    /// Escaped sequences are not consumed, as with
+    /// [`PeekableLineEndingExt::consume_line_ending`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, PeekableCharIndicesLineEndingExt};
+    ///
+    /// let mut it = "é\r\nx".char_indices().peekable();
+    /// it.next();
+    ///
+    /// assert_eq!(
+    ///     it.consume_line_ending_with_range(),
+    ///     Some((LineEnding::CRLF, 2..4))
+    /// );
+    /// assert_eq!(it.next(), Some((4, 'x')));
+    /// ```
+    fn consume_line_ending_with_range(&mut self) -> Option<(LineEnding, Range<usize>)>;
+}
+
+impl<I> PeekableCharIndicesLineEndingExt for Peekable<I>
+where
+    I: Iterator<Item = (usize, char)>,
+{
+    fn consume_line_ending_with_range(&mut self) -> Option<(LineEnding, Range<usize>)> {
+        if let Some((start, _)) = self.next_if(LineEndingUnit::is_cr) {
+            if let Some((lf, _)) = self.next_if(LineEndingUnit::is_lf) {
+                return Some((LineEnding::CRLF, start..lf + 1));
+            }
+            return Some((LineEnding::CR, start..start + 1));
+        }
+
+        let (start, _) = self.next_if(LineEndingUnit::is_lf)?;
+        Some((LineEnding::LF, start..start + 1))
+    }
 }
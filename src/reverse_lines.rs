@@ -0,0 +1,152 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+/// Default size of the blocks read by [`ReverseLines`].
+const DEFAULT_BLOCK_SIZE: usize = 8 * 1024;
+
+/// An iterator over the lines of a seekable source (such as a `File`), from
+/// last to first, for `tail`-like reading.
+///
+/// The source is read backwards in blocks, so only the tail that is actually
+/// iterated over is read. LF, CRLF, and CR are all recognized, including a
+/// CRLF whose `\r` and `\n` fall in different blocks. Lines are yielded
+/// without their line endings, and a trailing line ending does not produce a
+/// final empty line (matching
+/// [`StrLineEndingExt::lines_any_ending`](crate::StrLineEndingExt::lines_any_ending)).
+///
+/// Each byte is read and scanned once, so lines far longer than the block
+/// size (such as minified files or logs without line endings) take time
+/// linear in their length.
+///
+/// # Example
+///
+/// ```
+/// use std::io::Cursor;
+/// use line_ending::ReverseLines;
+///
+/// let log = Cursor::new("first\r\nsecond\nthird\rfourth\n");
+/// let last_two: Vec<String> = ReverseLines::new(log)
+///     .unwrap()
+///     .take(2)
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(last_two, vec!["fourth", "third"]);
+/// ```
+#[derive(Debug)]
+pub struct ReverseLines<R> {
+    reader: R,
+    block_size: usize,
+    /// Unyielded bytes, from `buf_start` up to the end of the remaining text.
+    buf: Vec<u8>,
+    /// Offset in the source of the first byte of `buf`.
+    buf_start: u64,
+}
+
+impl<R: Read + Seek> ReverseLines<R> {
+    /// Creates a reverse line iterator over the whole of `reader`.
+    pub fn new(reader: R) -> io::Result<Self> {
+        Self::with_block_size(reader, DEFAULT_BLOCK_SIZE)
+    }
+
+    /// Creates a reverse line iterator that reads `block_size` bytes at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is `0`.
+    pub fn with_block_size(mut reader: R, block_size: usize) -> io::Result<Self> {
+        assert!(block_size > 0, "block size must be greater than 0");
+
+        let len = reader.seek(SeekFrom::End(0))?;
+
+        Ok(Self {
+            reader,
+            block_size,
+            buf: Vec::new(),
+            buf_start: len,
+        })
+    }
+
+    /// Reads the block of the source preceding `buf_start`, returning `None`
+    /// at the start of the source.
+    fn read_block(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let size = (self.block_size as u64).min(self.buf_start) as usize;
+        if size == 0 {
+            return Ok(None);
+        }
+
+        self.buf_start -= size as u64;
+        self.reader.seek(SeekFrom::Start(self.buf_start))?;
+
+        let mut block = vec![0; size];
+        self.reader.read_exact(&mut block)?;
+
+        Ok(Some(block))
+    }
+
+    fn next_line(&mut self) -> io::Result<Option<String>> {
+        // Two bytes are needed to recognize a trailing CRLF; `buf` holds at
+        // most one byte here, so prepending to it is cheap
+        while self.buf.len() < 2 {
+            let Some(mut block) = self.read_block()? else {
+                break;
+            };
+            block.extend_from_slice(&self.buf);
+            self.buf = block;
+        }
+
+        if self.buf.is_empty() {
+            return Ok(None);
+        }
+
+        let mut end = if self.buf.ends_with(b"\r\n") {
+            self.buf.len() - 2
+        } else if self.buf.ends_with(b"\r") || self.buf.ends_with(b"\n") {
+            self.buf.len() - 1
+        } else {
+            self.buf.len()
+        };
+
+        // Pieces of the line, from last to first. Only each newly read block
+        // is scanned for the line's start, and no block is copied more than
+        // once, keeping long lines linear.
+        let mut pieces = Vec::new();
+        loop {
+            if let Some(pos) = self.buf[..end]
+                .iter()
+                .rposition(|&b| b == b'\r' || b == b'\n')
+            {
+                pieces.push(self.buf[pos + 1..end].to_vec());
+                self.buf.truncate(pos + 1);
+                break;
+            }
+
+            self.buf.truncate(end);
+            pieces.push(std::mem::take(&mut self.buf));
+
+            match self.read_block()? {
+                Some(block) => {
+                    self.buf = block;
+                    end = self.buf.len();
+                }
+                None => break,
+            }
+        }
+
+        let mut line = Vec::with_capacity(pieces.iter().map(Vec::len).sum());
+        for piece in pieces.iter().rev() {
+            line.extend_from_slice(piece);
+        }
+
+        String::from_utf8(line)
+            .map(Some)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+impl<R: Read + Seek> Iterator for ReverseLines<R> {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_line().transpose()
+    }
+}
@@ -0,0 +1,89 @@
+use crate::line_ending::EXPECTED;
+use crate::{LineEnding, LineEndingPolicy};
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt;
+
+/// Serializes a `LineEnding` as its canonical lowercase name (`"lf"`, `"crlf"`, or `"cr"`).
+impl Serialize for LineEnding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes a `LineEnding` from any string accepted by its `FromStr`
+/// implementation, e.g. `"crlf"`, `"windows"`, `"native"`, or `"\\r\\n"`.
+///
+/// `"auto"` is rejected, since auto-detection depends on the text being
+/// processed; use [`LineEndingPolicy`] for settings that accept it.
+impl<'de> Deserialize<'de> for LineEnding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(LineEndingVisitor)
+    }
+}
+
+struct LineEndingVisitor;
+
+impl Visitor<'_> for LineEndingVisitor {
+    type Value = LineEnding;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(EXPECTED)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Serializes a `LineEndingPolicy` as `"auto"` or as the fixed line ending's
+/// canonical name.
+impl Serialize for LineEndingPolicy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes a `LineEndingPolicy` from `"auto"` or any string accepted when
+/// deserializing a `LineEnding`.
+impl<'de> Deserialize<'de> for LineEndingPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(LineEndingPolicyVisitor)
+    }
+}
+
+struct LineEndingPolicyVisitor;
+
+impl Visitor<'_> for LineEndingPolicyVisitor {
+    type Value = LineEndingPolicy;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "\"auto\" or {}", EXPECTED)
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
@@ -0,0 +1,272 @@
+use crate::line_ending::{find_line_ending, split_first_line, split_last_line};
+use crate::{LineEnding, LogicalLines};
+use std::ops::Range;
+
+/// A trait to extend `str` (and, through deref, `String`) with line ending operations.
+///
+/// This mirrors the associated functions on [`LineEnding`] so they can be used
+/// in method chains.
+pub trait StrLineEndingExt {
+    /// Detects the predominant line ending style used in the string.
+    ///
+    /// Equivalent to `LineEnding::from(s)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, StrLineEndingExt};
+    ///
+    /// assert_eq!("first\r\nsecond".line_ending(), LineEnding::CRLF);
+    /// assert_eq!(String::from("first\nsecond").line_ending(), LineEnding::LF);
+    /// ```
+    fn line_ending(&self) -> LineEnding;
+
+    /// Converts all line endings in the string to the given type.
+    ///
+    /// Equivalent to `line_ending.apply(s)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, StrLineEndingExt};
+    ///
+    /// assert_eq!(
+    ///     "first\nsecond\rthird".to_line_ending(LineEnding::CRLF),
+    ///     "first\r\nsecond\r\nthird"
+    /// );
+    /// ```
+    fn to_line_ending(&self, line_ending: LineEnding) -> String;
+
+    /// Converts all line endings in the string to LF (`\n`).
+    ///
+    /// Equivalent to `LineEnding::normalize(s)`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::StrLineEndingExt;
+    ///
+    /// assert_eq!("first\r\nsecond\rthird".normalize_line_endings(), "first\nsecond\nthird");
+    /// ```
+    fn normalize_line_endings(&self) -> String;
+
+    /// Returns an iterator over the lines of the string, splitting on any
+    /// line ending (LF, CRLF, or CR), even when they are mixed.
+    ///
+    /// Like [`str::lines`], the line endings are not included in the yielded
+    /// lines, and a trailing line ending does not produce a final empty line.
+    /// Unlike [`str::lines`], a lone `\r` is treated as a line ending.
+    ///
+    /// (Named `lines_any_ending` because the deprecated inherent `str::lines_any`
+    /// would otherwise shadow this method.)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::StrLineEndingExt;
+    ///
+    /// let lines: Vec<&str> = "first\r\nsecond\rthird\nfourth\n".lines_any_ending().collect();
+    /// assert_eq!(lines, vec!["first", "second", "third", "fourth"]);
+    /// ```
+    fn lines_any_ending(&self) -> LinesAnyEnding<'_>;
+
+    /// Returns the lines in `lines` (zero-based) as borrowed slices, excluding
+    /// line endings.
+    ///
+    /// Line numbers match [`LineIndex`](crate::LineIndex): a trailing line
+    /// ending starts a final empty line. Returns `None` if the range extends
+    /// past the last line, as [`LineIndex::lines_in`](crate::LineIndex::lines_in)
+    /// does.
+    ///
+    /// Lines before the range are skipped with a fast forward scan, without
+    /// materializing them, and scanning stops at the end of the range. For
+    /// repeated lookups, build a [`LineIndex`](crate::LineIndex) instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::StrLineEndingExt;
+    ///
+    /// let text = "zero\r\none\ntwo\rthree\n";
+    /// assert_eq!(text.lines_in(1..3), Some(vec!["one", "two"]));
+    /// assert_eq!(text.lines_in(3..5), Some(vec!["three", ""]));
+    /// assert_eq!(text.lines_in(3..6), None);
+    /// ```
+    fn lines_in(&self, lines: Range<usize>) -> Option<Vec<&str>>;
+
+    /// Returns the lines in `lines` as borrowed slices, including each line's
+    /// line ending.
+    ///
+    /// See [`StrLineEndingExt::lines_in`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::StrLineEndingExt;
+    ///
+    /// let text = "zero\r\none\ntwo\rthree";
+    /// assert_eq!(text.lines_with_endings_in(1..3), Some(vec!["one\n", "two\r"]));
+    /// ```
+    fn lines_with_endings_in(&self, lines: Range<usize>) -> Option<Vec<&str>>;
+
+    /// Returns `true` if the string contains more than one type of line ending.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::StrLineEndingExt;
+    ///
+    /// assert!("first\r\nsecond\nthird".has_mixed_line_endings());
+    /// assert!(!"first\r\nsecond\r\nthird".has_mixed_line_endings());
+    /// assert!(!"no line endings".has_mixed_line_endings());
+    /// ```
+    fn has_mixed_line_endings(&self) -> bool;
+
+    /// Returns an iterator over the logical lines of the string, joining
+    /// physical lines that end in a backslash continuation (`\` immediately
+    /// followed by LF, CRLF, or CR), as in shell scripts, Makefiles, and C
+    /// preprocessor input.
+    ///
+    /// Each [`LogicalLine`](crate::LogicalLine) keeps its original text and
+    /// line ending, so the source can be reproduced exactly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::{LineEnding, StrLineEndingExt};
+    ///
+    /// let text = "CFLAGS = -O2 \\\r\n    -Wall\r\nall: main\n";
+    /// let lines: Vec<_> = text.logical_lines().collect();
+    ///
+    /// assert_eq!(lines.len(), 2);
+    /// assert_eq!(lines[0].joined(), "CFLAGS = -O2     -Wall");
+    /// assert_eq!(lines[0].lines(), 0..2);
+    /// assert_eq!(lines[0].line_ending(), Some(LineEnding::CRLF));
+    /// assert_eq!(lines[1].raw(), "all: main");
+    /// assert_eq!(lines[1].lines(), 2..3);
+    /// ```
+    fn logical_lines(&self) -> LogicalLines<'_>;
+}
+
+impl StrLineEndingExt for str {
+    fn line_ending(&self) -> LineEnding {
+        LineEnding::from(self)
+    }
+
+    fn to_line_ending(&self, line_ending: LineEnding) -> String {
+        line_ending.apply(self)
+    }
+
+    fn normalize_line_endings(&self) -> String {
+        LineEnding::normalize(self)
+    }
+
+    fn lines_any_ending(&self) -> LinesAnyEnding<'_> {
+        LinesAnyEnding { remaining: self }
+    }
+
+    fn lines_in(&self, lines: Range<usize>) -> Option<Vec<&str>> {
+        let lines = lines_in_range(self, lines)?;
+        Some(
+            lines
+                .into_iter()
+                .map(|(line, ending_len)| &line[..line.len() - ending_len])
+                .collect(),
+        )
+    }
+
+    fn lines_with_endings_in(&self, lines: Range<usize>) -> Option<Vec<&str>> {
+        let lines = lines_in_range(self, lines)?;
+        Some(lines.into_iter().map(|(line, _)| line).collect())
+    }
+
+    fn has_mixed_line_endings(&self) -> bool {
+        LineEnding::score_mixed_types(self)
+            .values()
+            .filter(|&&score| score > 0)
+            .count()
+            > 1
+    }
+
+    fn logical_lines(&self) -> LogicalLines<'_> {
+        LogicalLines::new(self)
+    }
+}
+
+/// Returns each line in `lines`, including its line ending, together with the
+/// length of that line ending, or `None` if the range extends past the last
+/// line.
+fn lines_in_range(s: &str, lines: Range<usize>) -> Option<Vec<(&str, usize)>> {
+    if lines.start > lines.end {
+        return None;
+    }
+
+    let mut result = Vec::with_capacity(lines.len());
+    let mut remaining = s;
+
+    for line in 0..lines.end {
+        let Some((pos, line_ending)) = find_line_ending(remaining.as_bytes()) else {
+            // The final line, which runs to the end of the text
+            if line >= lines.start {
+                result.push((remaining, 0));
+            }
+            return (line + 1 == lines.end).then_some(result);
+        };
+
+        let ending_len = line_ending.as_str().len();
+        let len = pos + ending_len;
+        if line >= lines.start {
+            result.push((&remaining[..len], ending_len));
+        }
+        remaining = &remaining[len..];
+    }
+
+    Some(result)
+}
+
+/// An iterator over the lines of a string, split on any line ending.
+///
+/// Created by [`StrLineEndingExt::lines_any_ending`].
+#[derive(Debug, Clone)]
+pub struct LinesAnyEnding<'a> {
+    remaining: &'a str,
+}
+
+impl<'a> Iterator for LinesAnyEnding<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let (end, next_start) = split_first_line(self.remaining.as_bytes());
+        let line = &self.remaining[..end];
+        self.remaining = &self.remaining[next_start..];
+        Some(line)
+    }
+}
+
+impl DoubleEndedIterator for LinesAnyEnding<'_> {
+    /// Yields lines from the end of the string, allowing `tail`-like reading
+    /// with `.rev()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::StrLineEndingExt;
+    ///
+    /// let last_two: Vec<&str> = "one\r\ntwo\rthree\n".lines_any_ending().rev().take(2).collect();
+    /// assert_eq!(last_two, vec!["three", "two"]);
+    /// ```
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining.is_empty() {
+            return None;
+        }
+
+        let (start, end) = split_last_line(self.remaining.as_bytes());
+        let line = &self.remaining[start..end];
+        self.remaining = &self.remaining[..start];
+        Some(line)
+    }
+}
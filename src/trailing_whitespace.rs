@@ -0,0 +1,99 @@
+use crate::line_ending::split_first_line;
+use crate::LineEnding;
+
+/// Trims trailing whitespace from each line of `s`, writing each line ending
+/// as produced by `line_ending` (which receives the original line ending).
+///
+/// With `keep_lone_cr_lines`, a whitespace-only line between a lone CR and an
+/// LF keeps its whitespace: if the original line endings are written back,
+/// removing it would join them into a single CRLF and lose a line.
+fn trim_lines<F>(s: &str, keep_lone_cr_lines: bool, mut line_ending: F) -> String
+where
+    F: FnMut(&str) -> &str,
+{
+    let mut result = String::with_capacity(s.len());
+    let mut remaining = s;
+    let mut after_lone_cr = false;
+
+    while !remaining.is_empty() {
+        let (end, next_start) = split_first_line(remaining.as_bytes());
+        let content = &remaining[..end];
+        let original = &remaining[end..next_start];
+
+        // The content contains no `\r` or `\n`, so only real whitespace is trimmed
+        let trimmed = content.trim_end();
+        if keep_lone_cr_lines && trimmed.is_empty() && after_lone_cr && original == "\n" {
+            result.push_str(content);
+        } else {
+            result.push_str(trimmed);
+        }
+        result.push_str(line_ending(original));
+
+        after_lone_cr = original == "\r";
+        remaining = &remaining[next_start..];
+    }
+
+    result
+}
+
+impl LineEnding {
+    /// Removes trailing whitespace from every line, preserving each line's
+    /// original line ending.
+    ///
+    /// Unlike calling `trim_end` on each line, the `\r` of a CRLF is recognized
+    /// as part of the line ending rather than as whitespace, so CRLF text stays
+    /// CRLF (and mixed text stays mixed).
+    ///
+    /// The one exception is a whitespace-only line after a lone CR and ending
+    /// in LF (e.g. `"a\r \nb"`), which is left as is, since trimming it would
+    /// turn its CR and LF into a single CRLF.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let text = "first  \r\nsecond\t\nthird \rfourth  ";
+    /// assert_eq!(
+    ///     LineEnding::trim_trailing_whitespace(text),
+    ///     "first\r\nsecond\nthird\rfourth"
+    /// );
+    /// ```
+    pub fn trim_trailing_whitespace(s: &str) -> String {
+        trim_lines(s, true, |original| original)
+    }
+
+    /// Removes trailing whitespace from every line and applies this line ending
+    /// type, in a single pass.
+    ///
+    /// Equivalent to `LineEnding::trim_trailing_whitespace(&self.apply(s))`.
+    /// Since every line ending is rewritten, whitespace-only lines between a
+    /// lone CR and an LF are trimmed as well; unlike with
+    /// [`LineEnding::trim_trailing_whitespace`], they cannot merge into a CRLF.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use line_ending::LineEnding;
+    ///
+    /// let text = "first  \r\nsecond\t\nthird \rfourth  ";
+    /// assert_eq!(
+    ///     LineEnding::LF.apply_trimming_trailing_whitespace(text),
+    ///     "first\nsecond\nthird\nfourth"
+    /// );
+    /// ```
+    pub fn apply_trimming_trailing_whitespace(&self, s: &str) -> String {
+        let target = self.as_str();
+        trim_lines(
+            s,
+            false,
+            |original| {
+                if original.is_empty() {
+                    ""
+                } else {
+                    target
+                }
+            },
+        )
+    }
+}
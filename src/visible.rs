@@ -0,0 +1,144 @@
+use crate::line_ending::find_line_ending;
+use crate::LineEnding;
+use std::fmt;
+
+/// The markers used to render each line ending type visibly.
+///
+/// Used with [`VisibleLineEndings`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Markers<'a> {
+    /// Marker for CRLF (`\r\n`).
+    pub crlf: &'a str,
+    /// Marker for LF (`\n`).
+    pub lf: &'a str,
+    /// Marker for CR (`\r`).
+    pub cr: &'a str,
+}
+
+impl Markers<'static> {
+    /// Unicode control pictures: `␍␊`, `␊`, and `␍`.
+    pub const CONTROL_PICTURES: Self = Self {
+        crlf: "␍␊",
+        lf: "␊",
+        cr: "␍",
+    };
+
+    /// Rust-style escapes: `\r\n`, `\n`, and `\r`.
+    pub const ESCAPED: Self = Self {
+        crlf: "\\r\\n",
+        lf: "\\n",
+        cr: "\\r",
+    };
+
+    /// `cat -A` style: `^M$`, `$`, and `^M`.
+    pub const CARET: Self = Self {
+        crlf: "^M$",
+        lf: "$",
+        cr: "^M",
+    };
+
+    /// A return symbol (`⏎`) for every line ending type. Combine with
+    /// [`VisibleLineEndings::annotate`] to tell the types apart.
+    pub const RETURN_SYMBOL: Self = Self {
+        crlf: "⏎",
+        lf: "⏎",
+        cr: "⏎",
+    };
+}
+
+impl<'a> Markers<'a> {
+    /// Returns the marker for the given line ending type.
+    pub fn for_line_ending(&self, line_ending: LineEnding) -> &'a str {
+        match line_ending {
+            LineEnding::CRLF => self.crlf,
+            LineEnding::LF => self.lf,
+            LineEnding::CR => self.cr,
+        }
+    }
+}
+
+impl Default for Markers<'_> {
+    fn default() -> Self {
+        Markers::CONTROL_PICTURES
+    }
+}
+
+/// A [`Display`](fmt::Display) wrapper that renders text with each line ending
+/// shown as a visible marker, for debugging mixed line endings.
+///
+/// Each line ending is replaced by its marker followed by a real `\n`, so the
+/// output keeps one line per line of input (like `cat -A`). Optionally, each
+/// line can be annotated with the name of its line ending.
+///
+/// # Example
+///
+/// ```
+/// use line_ending::{Markers, VisibleLineEndings};
+///
+/// let text = "first\r\nsecond\nthird\rfourth";
+///
+/// assert_eq!(
+///     VisibleLineEndings::new(text).to_string(),
+///     "first␍␊\nsecond␊\nthird␍\nfourth"
+/// );
+/// assert_eq!(
+///     VisibleLineEndings::new(text).markers(Markers::CARET).to_string(),
+///     "first^M$\nsecond$\nthird^M\nfourth"
+/// );
+/// assert_eq!(
+///     VisibleLineEndings::new(text)
+///         .markers(Markers::RETURN_SYMBOL)
+///         .annotate(true)
+///         .to_string(),
+///     "first⏎  [CRLF]\nsecond⏎  [LF]\nthird⏎  [CR]\nfourth"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct VisibleLineEndings<'a> {
+    text: &'a str,
+    markers: Markers<'a>,
+    annotate: bool,
+}
+
+impl<'a> VisibleLineEndings<'a> {
+    /// Wraps `text`, rendering line endings with [`Markers::CONTROL_PICTURES`].
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            markers: Markers::default(),
+            annotate: false,
+        }
+    }
+
+    /// Sets the markers used to render line endings.
+    pub fn markers(mut self, markers: Markers<'a>) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Sets whether each terminated line is annotated with the name of its
+    /// line ending (e.g. `[CRLF]`).
+    pub fn annotate(mut self, annotate: bool) -> Self {
+        self.annotate = annotate;
+        self
+    }
+}
+
+impl fmt::Display for VisibleLineEndings<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut remaining = self.text;
+
+        while let Some((pos, line_ending)) = find_line_ending(remaining.as_bytes()) {
+            f.write_str(&remaining[..pos])?;
+            f.write_str(self.markers.for_line_ending(line_ending))?;
+            if self.annotate {
+                write!(f, "  [{}]", line_ending)?;
+            }
+            f.write_str("\n")?;
+
+            remaining = &remaining[pos + line_ending.as_str().len()..];
+        }
+
+        f.write_str(remaining)
+    }
+}
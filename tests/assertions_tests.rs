@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{assert_eq_ignoring_line_endings, assert_eq_line_endings};
+    use std::panic;
+
+    fn panic_message<F: FnOnce() + panic::UnwindSafe>(f: F) -> String {
+        let payload = panic::catch_unwind(f).expect_err("assertion should have failed");
+
+        payload
+            .downcast_ref::<String>()
+            .cloned()
+            .expect("panic payload should be a String")
+    }
+
+    #[test]
+    fn passes_on_equal_strings() {
+        assert_eq_line_endings!("a\r\nb", "a\r\nb");
+        assert_eq_line_endings!(String::from("a\nb"), "a\nb", "with {}", "message");
+        assert_eq_ignoring_line_endings!("a\r\nb\r", String::from("a\nb\n"));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `left == right` failed")]
+    fn fails_on_line_ending_differences() {
+        assert_eq_line_endings!("a\r\nb", "a\nb");
+    }
+
+    #[test]
+    fn shows_visible_line_ending_markers() {
+        let message = panic_message(|| assert_eq_line_endings!("a\r\nb\rc\n", "a\nb\rc\r\n"));
+
+        assert_eq!(
+            message,
+            "assertion `left == right` failed\n\
+             line 1:\n   left: a␍␊\n  right: a␊\n\
+             line 3:\n   left: c␊\n  right: c␍␊"
+        );
+    }
+
+    #[test]
+    fn reports_content_differences_when_ignoring_line_endings() {
+        let message = panic_message(|| {
+            assert_eq_ignoring_line_endings!("same\r\nleft\r\n", "same\nright\n", "case {}", 7)
+        });
+
+        assert_eq!(
+            message,
+            "assertion `left == right` failed (ignoring line endings): case 7\n\
+             line 2:\n   left: left␍␊\n  right: right␊"
+        );
+    }
+
+    #[test]
+    fn reports_missing_lines() {
+        let message = panic_message(|| assert_eq_ignoring_line_endings!("a\r\n", "a\n\n"));
+
+        assert!(message.contains("line 2:\n   left: <missing>\n  right: ␊"));
+    }
+
+    #[test]
+    fn reports_an_inserted_line_once() {
+        let left: String = (1..=50).map(|i| format!("line {}\r\n", i)).collect();
+        let right = left.replacen("line 10\r\n", "line 10\r\ninserted\r\n", 1);
+
+        let message = panic_message(move || assert_eq_line_endings!(left, right));
+
+        assert_eq!(
+            message,
+            "assertion `left == right` failed\n\
+             line 11:\n   left: <missing>\n  right: inserted␍␊"
+        );
+    }
+
+    #[test]
+    fn aligns_changes_after_a_removed_line() {
+        let left = "a\nremoved\nb\nc\nd\n";
+        let right = "a\nb\nc\r\nd\n";
+
+        let message = panic_message(|| assert_eq_line_endings!(left, right));
+
+        assert_eq!(
+            message,
+            "assertion `left == right` failed\n\
+             line 2:\n   left: removed␊\n  right: <missing>\n\
+             left line 4, right line 3:\n   left: c␊\n  right: c␍␊"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::LineEnding;
+
+    #[test]
+    fn reports_rewritten_and_unchanged_line_endings() {
+        let mixed = "line1\nline2\r\nline3\rline4\nline5\n";
+
+        let (text, stats) = LineEnding::CRLF.apply_with_stats(mixed);
+        assert_eq!(text, LineEnding::CRLF.apply(mixed));
+        assert_eq!(stats.target, LineEnding::CRLF);
+        assert_eq!(stats.rewritten[&LineEnding::LF], 3);
+        assert_eq!(stats.rewritten[&LineEnding::CR], 1);
+        assert_eq!(stats.rewritten[&LineEnding::CRLF], 0);
+        assert_eq!(stats.unchanged, 1);
+        assert_eq!(stats.total_rewritten(), 4);
+        assert_eq!(stats.total(), 5);
+        assert!(stats.changed);
+
+        let (text, stats) = LineEnding::LF.apply_with_stats(mixed);
+        assert_eq!(text, LineEnding::LF.apply(mixed));
+        assert_eq!(stats.rewritten[&LineEnding::CRLF], 1);
+        assert_eq!(stats.rewritten[&LineEnding::CR], 1);
+        assert_eq!(stats.unchanged, 3);
+    }
+
+    #[test]
+    fn reports_unchanged_text() {
+        let (text, stats) = LineEnding::CR.apply_with_stats("a\rb\rc");
+        assert_eq!(text, "a\rb\rc");
+        assert_eq!(stats.unchanged, 2);
+        assert_eq!(stats.total_rewritten(), 0);
+        assert!(!stats.changed);
+
+        let (text, stats) = LineEnding::LF.apply_with_stats("");
+        assert_eq!(text, "");
+        assert_eq!(stats.total(), 0);
+        assert!(!stats.changed);
+    }
+
+    #[test]
+    fn in_place_stats_match_copying_stats() {
+        let mixed = "first\r\nsecond\rthird\n";
+
+        for line_ending in LineEnding::ALL {
+            let (expected_text, expected_stats) = line_ending.apply_with_stats(mixed);
+
+            let mut text = mixed.to_string();
+            let stats = line_ending.apply_in_place_with_stats(&mut text);
+
+            assert_eq!(text, expected_text);
+            assert_eq!(stats, expected_stats);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{CsvRecords, LineEnding};
+
+    #[test]
+    fn keeps_line_endings_inside_quotes_as_content() {
+        let csv = "name,address\r\nAda,\"1 Main St\r\nLondon\"\r\nBob,\"x\ny\rz\"\n";
+        let records: Vec<_> = CsvRecords::new(csv).collect();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1].as_str(), "Ada,\"1 Main St\r\nLondon\"");
+        assert_eq!(records[1].line_ending(), Some(LineEnding::CRLF));
+        assert_eq!(records[2].as_str(), "Bob,\"x\ny\rz\"");
+        assert_eq!(records[2].line_ending(), Some(LineEnding::LF));
+
+        for record in &records {
+            assert_eq!(&csv[record.range()], record.as_str());
+        }
+    }
+
+    #[test]
+    fn handles_doubled_quotes() {
+        let csv = "\"a \"\"quoted\"\"\r\nvalue\",b\r\n\"\"\r\nnext";
+        let records: Vec<&str> = CsvRecords::new(csv).map(|r| r.as_str()).collect();
+
+        assert_eq!(
+            records,
+            vec!["\"a \"\"quoted\"\"\r\nvalue\",b", "\"\"", "next"]
+        );
+    }
+
+    #[test]
+    fn supports_custom_quote_character() {
+        let csv = "'a\nb',\"c\nd\"";
+        let records: Vec<&str> = CsvRecords::new(csv)
+            .quote('\'')
+            .map(|r| r.as_str())
+            .collect();
+
+        assert_eq!(records, vec!["'a\nb',\"c", "d\""]);
+    }
+
+    #[test]
+    fn unterminated_quote_extends_to_end_of_text() {
+        let records: Vec<_> = CsvRecords::new("a\n\"b\nc\n").collect();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].as_str(), "\"b\nc\n");
+        assert_eq!(records[1].line_ending(), None);
+    }
+
+    #[test]
+    fn detects_record_line_ending_outside_quotes() {
+        assert_eq!(
+            CsvRecords::new("a,\"1\n2\n3\"\rb\rc").record_line_ending(),
+            LineEnding::CR
+        );
+        assert_eq!(
+            CsvRecords::new("a,\"1\n2\"").record_line_ending(),
+            LineEnding::CRLF
+        );
+        assert_eq!(CsvRecords::new("").record_line_ending(), LineEnding::CRLF);
+    }
+
+    #[test]
+    fn applies_record_and_field_line_endings_separately() {
+        let csv = "a,\"x\ry\"\nb,\"p\r\nq\"\rc";
+
+        assert_eq!(
+            CsvRecords::new(csv).apply(LineEnding::CRLF, None),
+            "a,\"x\ry\"\r\nb,\"p\r\nq\"\r\nc"
+        );
+        assert_eq!(
+            CsvRecords::new(csv).apply(LineEnding::LF, Some(LineEnding::CRLF)),
+            "a,\"x\r\ny\"\nb,\"p\r\nq\"\nc"
+        );
+        assert_eq!(CsvRecords::new("").apply(LineEnding::CRLF, None), "");
+    }
+
+    #[test]
+    fn treats_quotes_inside_unquoted_fields_as_content() {
+        let csv = "size,5\" floppy\nnext,row\nlast,row\n";
+        let records: Vec<&str> = CsvRecords::new(csv).map(|r| r.as_str()).collect();
+
+        assert_eq!(records, vec!["size,5\" floppy", "next,row", "last,row"]);
+        assert_eq!(CsvRecords::new(csv).record_line_ending(), LineEnding::LF);
+        assert_eq!(
+            CsvRecords::new(csv).apply(LineEnding::CRLF, None),
+            "size,5\" floppy\r\nnext,row\r\nlast,row\r\n"
+        );
+    }
+
+    #[test]
+    fn opens_quoted_fields_only_after_the_delimiter() {
+        let csv = "a;\"x\ny\";b\"\nc";
+
+        let records: Vec<&str> = CsvRecords::new(csv).map(|r| r.as_str()).collect();
+        assert_eq!(records, vec!["a;\"x", "y\";b\"", "c"]);
+
+        let records: Vec<&str> = CsvRecords::new(csv)
+            .delimiter(';')
+            .map(|r| r.as_str())
+            .collect();
+        assert_eq!(records, vec!["a;\"x\ny\";b\"", "c"]);
+    }
+}
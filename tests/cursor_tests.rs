@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{ColumnUnit, LineEnding, LineIndex, LineTrackingCursor, Position};
+
+    #[test]
+    fn tracks_positions_across_mixed_line_endings() {
+        let text = "ab\r\ncd\ne\rf";
+        let mut cursor = LineTrackingCursor::new(text.chars());
+        let mut line_endings = Vec::new();
+
+        loop {
+            if let Some(le) = cursor.consume_line_ending() {
+                line_endings.push(le);
+            } else if cursor.next().is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(
+            line_endings,
+            vec![LineEnding::CRLF, LineEnding::LF, LineEnding::CR]
+        );
+        assert_eq!(
+            cursor.position(),
+            Position {
+                offset: text.len(),
+                line: 3,
+                column: 1
+            }
+        );
+    }
+
+    #[test]
+    fn counts_crlf_as_one_line_break_when_consumed_by_next() {
+        let text = "a\r\nb\r\rc\n\rd";
+        let index = LineIndex::new(text);
+        let mut cursor = LineTrackingCursor::new(text.chars());
+
+        loop {
+            let position = cursor.position();
+            let expected = index.line_col(position.offset, ColumnUnit::Char).unwrap();
+            // Between the `\r` and `\n` of a CRLF the cursor is already on the next line
+            let in_crlf = text[..position.offset].ends_with('\r')
+                && text[position.offset..].starts_with('\n');
+
+            if !in_crlf {
+                assert_eq!(position.line, expected.line, "at {}", position.offset);
+                assert_eq!(position.column, expected.column, "at {}", position.offset);
+            }
+
+            if cursor.next().is_none() {
+                break;
+            }
+        }
+
+        assert_eq!(cursor.position().line, index.line_count() - 1);
+    }
+
+    #[test]
+    fn counts_columns_in_chars_and_offsets_in_bytes() {
+        let mut cursor = LineTrackingCursor::new("é😀x".chars());
+        cursor.next();
+        cursor.next();
+
+        assert_eq!(
+            cursor.position(),
+            Position {
+                offset: 6,
+                line: 0,
+                column: 2
+            }
+        );
+        assert_eq!(cursor.peek(), Some(&'x'));
+    }
+
+    #[test]
+    fn captures_token_spans() {
+        let text = "foo\r\n  bar";
+        let mut cursor = LineTrackingCursor::new(text.chars());
+        let mut spans = Vec::new();
+
+        while cursor.peek().is_some() {
+            if cursor.consume_line_ending().is_some() {
+                continue;
+            }
+            if cursor.peek().is_some_and(|ch| ch.is_whitespace()) {
+                cursor.next();
+                continue;
+            }
+
+            let start = cursor.position();
+            while cursor.peek().is_some_and(|ch| ch.is_alphanumeric()) {
+                cursor.next();
+            }
+            spans.push(cursor.span_from(start));
+        }
+
+        assert_eq!(spans.len(), 2);
+        assert_eq!(&text[spans[0].range()], "foo");
+        assert_eq!(&text[spans[1].range()], "bar");
+        assert_eq!((spans[1].start.line, spans[1].start.column), (1, 2));
+        assert_eq!((spans[1].end.line, spans[1].end.column), (1, 5));
+    }
+}
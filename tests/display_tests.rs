@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{LineEnding, LineEndingWriter};
+    use std::fmt::Write;
+
+    const SAMPLES: [&str; 6] = [
+        "",
+        "plain",
+        "first line\r\nsecond line\rthird line\nfourth line\n",
+        "\r\r\n\n\r",
+        "a\n\rb",
+        "escaped\\r\\n\r\n",
+    ];
+
+    #[test]
+    fn display_matches_apply() {
+        for sample in SAMPLES {
+            for line_ending in LineEnding::ALL {
+                assert_eq!(
+                    line_ending.display(sample).to_string(),
+                    line_ending.apply(sample),
+                    "{:?}",
+                    sample
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn writer_matches_apply_for_any_chunking() {
+        for sample in SAMPLES {
+            for line_ending in LineEnding::ALL {
+                for chunk in 1..=4 {
+                    let mut writer = LineEndingWriter::new(String::new(), line_ending);
+                    let chars: Vec<char> = sample.chars().collect();
+
+                    for piece in chars.chunks(chunk) {
+                        writer.write_str(&piece.iter().collect::<String>()).unwrap();
+                    }
+
+                    assert_eq!(
+                        writer.into_inner(),
+                        line_ending.apply(sample),
+                        "{:?} in chunks of {}",
+                        sample,
+                        chunk
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn writer_handles_crlf_split_across_writes() {
+        let mut writer = LineEndingWriter::new(String::new(), LineEnding::LF);
+        writer.write_str("a\r").unwrap();
+        assert_eq!(writer.get_ref(), "a\n");
+
+        writer.write_str("").unwrap();
+        writer.write_str("\nb\r").unwrap();
+        writer.write_str("\r").unwrap();
+        writer.write_str("c").unwrap();
+
+        assert_eq!(writer.into_inner(), "a\nb\n\nc");
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{EscapeAwareLineEndings, LineEnding, ScanItem};
+
+    /// Collects the items, rendering content runs as strings for readability.
+    fn scan(text: &str, escape_char: Option<char>) -> Vec<String> {
+        let mut output = Vec::new();
+        let mut content = String::new();
+
+        for item in EscapeAwareLineEndings::new(text.chars()).escape_char(escape_char) {
+            match item {
+                ScanItem::Char(ch) => content.push(ch),
+                ScanItem::LineEnding(le) => {
+                    output.push(std::mem::take(&mut content));
+                    output.push(format!("<{}>", le));
+                }
+                ScanItem::EscapedLineEnding(le) => {
+                    output.push(std::mem::take(&mut content));
+                    output.push(format!("<escaped {}>", le));
+                }
+            }
+        }
+        output.push(content);
+
+        output
+    }
+
+    #[test]
+    fn reports_actual_and_escaped_line_endings() {
+        assert_eq!(
+            scan("a\r\nb\\nc\\r\\nd\\re", Some('\\')),
+            vec![
+                "a",
+                "<CRLF>",
+                "b",
+                "<escaped LF>",
+                "c",
+                "<escaped CRLF>",
+                "d",
+                "<escaped CR>",
+                "e"
+            ]
+        );
+    }
+
+    #[test]
+    fn respects_backslash_parity() {
+        // `\\n` is an escaped backslash followed by `n`
+        assert_eq!(scan("a\\\\nb", Some('\\')), vec!["a\\\\nb"]);
+
+        // `\\\n` is an escaped backslash followed by an escaped LF
+        assert_eq!(
+            scan("a\\\\\\nb", Some('\\')),
+            vec!["a\\\\", "<escaped LF>", "b"]
+        );
+
+        // `\\` followed by a real line ending
+        assert_eq!(scan("a\\\\\nb", Some('\\')), vec!["a\\\\", "<LF>", "b"]);
+    }
+
+    #[test]
+    fn handles_escaped_cr_followed_by_other_escapes() {
+        assert_eq!(
+            scan("\\r\\\\x", Some('\\')),
+            vec!["", "<escaped CR>", "\\\\x"]
+        );
+        assert_eq!(scan("\\r\\t", Some('\\')), vec!["", "<escaped CR>", "\\t"]);
+        assert_eq!(
+            scan("\\r\\r\\n", Some('\\')),
+            vec!["", "<escaped CR>", "", "<escaped CRLF>", ""]
+        );
+        assert_eq!(scan("\\r\\", Some('\\')), vec!["", "<escaped CR>", "\\"]);
+    }
+
+    #[test]
+    fn supports_custom_and_disabled_escape_chars() {
+        assert_eq!(
+            scan("a^nb\\nc", Some('^')),
+            vec!["a", "<escaped LF>", "b\\nc"]
+        );
+        assert_eq!(scan("a\\\nb", None), vec!["a\\", "<LF>", "b"]);
+        assert_eq!(scan("a\\nb", None), vec!["a\\nb"]);
+    }
+
+    #[test]
+    fn works_with_non_clone_iterators() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        for ch in "x\\ny\r".chars() {
+            sender.send(ch).unwrap();
+        }
+        drop(sender);
+
+        let items: Vec<ScanItem> = EscapeAwareLineEndings::new(receiver.into_iter()).collect();
+        assert_eq!(
+            items,
+            vec![
+                ScanItem::Char('x'),
+                ScanItem::EscapedLineEnding(LineEnding::LF),
+                ScanItem::Char('y'),
+                ScanItem::LineEnding(LineEnding::CR),
+            ]
+        );
+    }
+}
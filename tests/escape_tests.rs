@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::LineEnding;
+
+    #[test]
+    fn escapes_real_line_endings() {
+        assert_eq!(
+            LineEnding::escape_line_endings("a\r\nb\nc\rd"),
+            "a\\r\\nb\\nc\\rd"
+        );
+        assert_eq!(LineEnding::escape_line_endings("no endings"), "no endings");
+
+        let escaped = LineEnding::escape_line_endings("multi\r\nline\n");
+        assert_eq!(LineEnding::split(&escaped).len(), 1);
+    }
+
+    #[test]
+    fn escapes_backslashes() {
+        assert_eq!(LineEnding::escape_line_endings("a\\b"), "a\\\\b");
+        assert_eq!(LineEnding::escape_line_endings("a\\n"), "a\\\\n");
+    }
+
+    #[test]
+    fn unescapes_respecting_backslash_parity() {
+        assert_eq!(LineEnding::unescape_line_endings("a\\nb"), "a\nb");
+        assert_eq!(LineEnding::unescape_line_endings("a\\\\nb"), "a\\nb");
+        assert_eq!(LineEnding::unescape_line_endings("a\\\\\\nb"), "a\\\nb");
+        assert_eq!(LineEnding::unescape_line_endings("a\\\\\\\\nb"), "a\\\\nb");
+        assert_eq!(LineEnding::unescape_line_endings("\\r\\n"), "\r\n");
+    }
+
+    #[test]
+    fn leaves_other_escapes_alone() {
+        assert_eq!(LineEnding::unescape_line_endings("\\t\\u{1}"), "\\t\\u{1}");
+        assert_eq!(
+            LineEnding::unescape_line_endings("trailing\\"),
+            "trailing\\"
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let samples = [
+            "",
+            "plain",
+            "a\r\nb\nc\rd",
+            "literal \\n and real \n",
+            "\\\\\r\\r\\\n",
+            "ünïcödé\r\n",
+        ];
+
+        for sample in samples {
+            let escaped = LineEnding::escape_line_endings(sample);
+            assert!(!escaped.contains(['\r', '\n']), "{:?}", escaped);
+            assert_eq!(LineEnding::unescape_line_endings(&escaped), sample);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{FileLineIndex, LineEnding, LineIndex};
+    use std::io::{self, Cursor, Read};
+
+    /// A reader that yields at most `chunk` bytes per read, to exercise
+    /// line endings split across reads.
+    struct ChunkedReader<R> {
+        inner: R,
+        chunk: usize,
+    }
+
+    impl<R: Read> Read for ChunkedReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(self.chunk);
+            self.inner.read(&mut buf[..len])
+        }
+    }
+
+    #[test]
+    fn matches_in_memory_line_index() {
+        let text = "zero\r\none\ntwo\rthree\r\n\r\rsix\r";
+        let in_memory = LineIndex::new(text);
+
+        for chunk in 1..8 {
+            let reader = ChunkedReader {
+                inner: Cursor::new(text),
+                chunk,
+            };
+            let index = FileLineIndex::from_reader(reader).unwrap();
+
+            assert_eq!(
+                index.line_count(),
+                in_memory.line_count(),
+                "chunk {}",
+                chunk
+            );
+            assert_eq!(index.len(), text.len() as u64);
+
+            for line in 0..index.line_count() {
+                let expected = in_memory.line_range_with_ending(line).unwrap();
+                assert_eq!(
+                    index.line_range_with_ending(line),
+                    Some(expected.start as u64..expected.end as u64)
+                );
+                assert_eq!(index.line_ending(line), in_memory.line_ending(line));
+            }
+        }
+    }
+
+    #[test]
+    fn reads_line_ranges_by_seeking() {
+        let text = "zero\r\none\ntwo\rthree\n";
+        let mut source = Cursor::new(text);
+        let index = FileLineIndex::from_reader(&mut source).unwrap();
+
+        assert_eq!(index.line_count(), 5);
+        assert_eq!(index.line_ending(3), Some(LineEnding::LF));
+        assert_eq!(
+            index.read_lines(&mut source, 1..4).unwrap(),
+            vec!["one", "two", "three"]
+        );
+        assert_eq!(
+            index.read_lines_with_endings(&mut source, 0..2).unwrap(),
+            vec!["zero\r\n", "one\n"]
+        );
+        assert_eq!(index.read_lines(&mut source, 4..5).unwrap(), vec![""]);
+        assert!(index.read_lines(&mut source, 2..2).unwrap().is_empty());
+
+        let err = index.read_lines(&mut source, 3..6).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn reads_from_a_file() {
+        use std::fs::{self, File};
+
+        let path = std::env::temp_dir().join(format!(
+            "line_ending_file_line_index_{}.txt",
+            std::process::id()
+        ));
+        let contents: String = (0..20_000).map(|i| format!("line {}\r\n", i)).collect();
+        fs::write(&path, &contents).unwrap();
+
+        let mut file = File::open(&path).unwrap();
+        let index = FileLineIndex::from_reader(&mut file).unwrap();
+        let lines = index.read_lines(&mut file, 10_000..10_003);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(index.line_count(), 20_001);
+        assert_eq!(
+            lines.unwrap(),
+            vec!["line 10000", "line 10001", "line 10002"]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_utf8() {
+        let mut source = Cursor::new(vec![b'a', b'\n', 0xff, b'\n']);
+        let index = FileLineIndex::from_reader(&mut source).unwrap();
+
+        assert_eq!(index.read_lines(&mut source, 0..1).unwrap(), vec!["a"]);
+        assert_eq!(
+            index.read_lines(&mut source, 1..2).unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+
+    #[test]
+    fn scan_lines_matches_in_memory_line_index() {
+        let text = "zero\r\none\ntwo\rthree\r\n\r\rsix\r";
+        let in_memory = LineIndex::new(text);
+
+        for chunk in 1..8 {
+            for start in 0..=in_memory.line_count() {
+                for end in start..=in_memory.line_count() + 1 {
+                    let reader = ChunkedReader {
+                        inner: Cursor::new(text),
+                        chunk,
+                    };
+                    let expected = in_memory
+                        .lines_in(start..end)
+                        .map(|lines| lines.into_iter().map(String::from).collect::<Vec<_>>());
+
+                    match FileLineIndex::scan_lines(reader, start..end) {
+                        Ok(lines) => assert_eq!(Some(lines), expected),
+                        Err(e) => {
+                            assert_eq!(expected, None, "{}..{}", start, end);
+                            assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn scan_lines_stops_at_end_of_range() {
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("read past range"))
+            }
+        }
+
+        let source = Cursor::new("zero\r\none\n").chain(FailingReader);
+        assert_eq!(
+            FileLineIndex::scan_lines(source, 1..2).unwrap(),
+            vec!["one"]
+        );
+
+        let source = Cursor::new("zero\r\none\n").chain(FailingReader);
+        assert!(FileLineIndex::scan_lines(source, 1..3).is_err());
+    }
+}
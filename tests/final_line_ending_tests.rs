@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{FinalLineEnding, LineEnding};
+
+    const POLICIES: [FinalLineEnding; 5] = [
+        FinalLineEnding::Preserve,
+        FinalLineEnding::Ensure,
+        FinalLineEnding::Strip,
+        FinalLineEnding::Collapse,
+        FinalLineEnding::Single,
+    ];
+
+    #[test]
+    fn ensures_final_line_ending() {
+        let policy = FinalLineEnding::Ensure;
+
+        assert_eq!(
+            LineEnding::CRLF.fix_final_line_ending("a\r\nb", policy),
+            "a\r\nb\r\n"
+        );
+        assert_eq!(LineEnding::CR.fix_final_line_ending("a", policy), "a\r");
+        assert_eq!(LineEnding::CRLF.fix_final_line_ending("a\n", policy), "a\n");
+        assert_eq!(
+            LineEnding::LF.fix_final_line_ending("a\n\n", policy),
+            "a\n\n"
+        );
+        assert_eq!(LineEnding::LF.fix_final_line_ending("", policy), "");
+    }
+
+    #[test]
+    fn strips_final_line_endings() {
+        let policy = FinalLineEnding::Strip;
+
+        assert_eq!(
+            LineEnding::LF.fix_final_line_ending("a\r\nb\r\n", policy),
+            "a\r\nb"
+        );
+        assert_eq!(
+            LineEnding::LF.fix_final_line_ending("a\n\r\n\r", policy),
+            "a"
+        );
+        assert_eq!(LineEnding::LF.fix_final_line_ending("a", policy), "a");
+        assert_eq!(LineEnding::LF.fix_final_line_ending("\n\n", policy), "");
+    }
+
+    #[test]
+    fn collapses_trailing_blank_lines() {
+        let policy = FinalLineEnding::Collapse;
+
+        assert_eq!(
+            LineEnding::LF.fix_final_line_ending("a\r\n\r\n\r\n", policy),
+            "a\r\n"
+        );
+        assert_eq!(
+            LineEnding::LF.fix_final_line_ending("a\r\n\n", policy),
+            "a\r\n"
+        );
+        assert_eq!(
+            LineEnding::LF.fix_final_line_ending("a\r\r\n", policy),
+            "a\r"
+        );
+        assert_eq!(LineEnding::LF.fix_final_line_ending("a", policy), "a");
+        assert_eq!(LineEnding::LF.fix_final_line_ending("\n\n", policy), "\n");
+    }
+
+    #[test]
+    fn ends_with_exactly_one_line_ending() {
+        let policy = FinalLineEnding::Single;
+
+        assert_eq!(LineEnding::CRLF.fix_final_line_ending("a", policy), "a\r\n");
+        assert_eq!(
+            LineEnding::CRLF.fix_final_line_ending("a\n\n", policy),
+            "a\n"
+        );
+        assert_eq!(
+            LineEnding::CRLF.fix_final_line_ending("a\r\n", policy),
+            "a\r\n"
+        );
+    }
+
+    #[test]
+    fn is_satisfied_by_matches_fix() {
+        let samples = [
+            "",
+            "a",
+            "a\n",
+            "a\r\n",
+            "a\r\n\r\n",
+            "a\r\r",
+            "\n",
+            "\r\n\n",
+        ];
+
+        for policy in POLICIES {
+            for sample in samples {
+                for line_ending in LineEnding::ALL {
+                    let fixed = line_ending.fix_final_line_ending(sample, policy);
+                    assert_eq!(
+                        policy.is_satisfied_by(sample),
+                        fixed == sample,
+                        "{:?} {:?}",
+                        policy,
+                        sample
+                    );
+                    assert!(policy.is_satisfied_by(&fixed), "{:?} {:?}", policy, sample);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn applies_conversion_and_policy_together() {
+        let text = "a\nb\r\n\r\n";
+
+        assert_eq!(
+            LineEnding::CRLF.apply_with_final_line_ending(text, FinalLineEnding::Preserve),
+            "a\r\nb\r\n\r\n"
+        );
+        assert_eq!(
+            LineEnding::CRLF.apply_with_final_line_ending(text, FinalLineEnding::Single),
+            "a\r\nb\r\n"
+        );
+        assert_eq!(
+            LineEnding::CR.apply_with_final_line_ending("a\nb", FinalLineEnding::Ensure),
+            "a\rb\r"
+        );
+        assert_eq!(
+            LineEnding::LF.apply_with_final_line_ending(text, FinalLineEnding::Strip),
+            "a\nb"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{LineEnding, LineEndingPolicy};
+
+    #[test]
+    fn parses_auto_and_fixed_line_endings() {
+        assert_eq!("auto".parse(), Ok(LineEndingPolicy::Auto));
+        assert_eq!("Auto".parse(), Ok(LineEndingPolicy::Auto));
+        assert_eq!(
+            "crlf".parse(),
+            Ok(LineEndingPolicy::Fixed(LineEnding::CRLF))
+        );
+        assert_eq!("\\n".parse(), Ok(LineEndingPolicy::Fixed(LineEnding::LF)));
+        assert!("detect".parse::<LineEndingPolicy>().is_err());
+    }
+
+    #[test]
+    fn displays_round_trip_through_parse() {
+        for policy in [
+            LineEndingPolicy::Auto,
+            LineEndingPolicy::Fixed(LineEnding::LF),
+            LineEndingPolicy::Fixed(LineEnding::CRLF),
+            LineEndingPolicy::Fixed(LineEnding::CR),
+        ] {
+            assert_eq!(policy.to_string().parse(), Ok(policy));
+        }
+        assert_eq!(LineEndingPolicy::Auto.to_string(), "auto");
+    }
+
+    #[test]
+    fn resolves_against_text() {
+        let text = "a\rb\rc\r\n";
+
+        assert_eq!(LineEndingPolicy::Auto.resolve(text), LineEnding::CR);
+        assert_eq!(LineEndingPolicy::Auto.resolve(""), LineEnding::CRLF);
+        assert_eq!(
+            LineEndingPolicy::from(LineEnding::LF).resolve(text),
+            LineEnding::LF
+        );
+        assert_eq!(LineEndingPolicy::Auto.apply(text), "a\rb\rc\r");
+        assert_eq!(LineEndingPolicy::default(), LineEndingPolicy::Auto);
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use line_ending::{LineEnding, LineEndingScores};
+    use line_ending::{LineEnding, LineEndingScores, ParseLineEndingError};
 
     fn get_readme_contents() -> String {
         use std::fs::File;
@@ -282,4 +282,102 @@ mod tests {
         // CRLF is composed of two characters, so this should panic.
         let _ = LineEnding::CRLF.as_char();
     }
+
+    #[test]
+    fn try_as_char_returns_none_for_crlf() {
+        assert_eq!(LineEnding::LF.try_as_char(), Some('\n'));
+        assert_eq!(LineEnding::CR.try_as_char(), Some('\r'));
+        assert_eq!(LineEnding::CRLF.try_as_char(), None);
+    }
+
+    #[test]
+    fn parses_names_aliases_and_literals() {
+        let cases = [
+            ("lf", LineEnding::LF),
+            ("LF", LineEnding::LF),
+            ("unix", LineEnding::LF),
+            ("\\n", LineEnding::LF),
+            ("\n", LineEnding::LF),
+            ("crlf", LineEnding::CRLF),
+            ("Windows", LineEnding::CRLF),
+            ("\\r\\n", LineEnding::CRLF),
+            ("\r\n", LineEnding::CRLF),
+            ("cr", LineEnding::CR),
+            ("MAC", LineEnding::CR),
+            ("\\r", LineEnding::CR),
+            ("\r", LineEnding::CR),
+            ("native", LineEnding::from_current_platform()),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(input.parse::<LineEnding>(), Ok(expected), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn parse_reports_invalid_input() {
+        let err: ParseLineEndingError = "auto".parse::<LineEnding>().unwrap_err();
+        assert_eq!(err.input(), "auto");
+        assert!(err.to_string().contains("\"auto\""));
+        for accepted in ["\"windows\"", "\"native\"", "\"\\r\\n\"", "literal"] {
+            assert!(err.to_string().contains(accepted), "{}", err);
+        }
+    }
+
+    #[test]
+    fn display_and_name_round_trip() {
+        for line_ending in LineEnding::ALL {
+            assert_eq!(
+                line_ending.to_string().parse::<LineEnding>(),
+                Ok(line_ending)
+            );
+            assert_eq!(line_ending.name().parse::<LineEnding>(), Ok(line_ending));
+        }
+
+        assert_eq!(LineEnding::CRLF.to_string(), "CRLF");
+        assert_eq!(LineEnding::CR.name(), "cr");
+    }
+
+    #[test]
+    fn apply_in_place_matches_apply() {
+        let inputs = [
+            "",
+            "no line endings",
+            "first line\r\nsecond line\rthird line\nfourth line\n",
+            "\r\r\n\n\r\n\r",
+            "ünïcödé\r\nlïnës\r",
+        ];
+
+        for input in inputs {
+            for line_ending in LineEnding::ALL {
+                let mut in_place = input.to_string();
+                line_ending.apply_in_place(&mut in_place);
+                assert_eq!(in_place, line_ending.apply(input), "{:?}", input);
+
+                let mut bytes = input.as_bytes().to_vec();
+                line_ending.apply_in_place_bytes(&mut bytes);
+                assert_eq!(bytes, line_ending.apply(input).into_bytes());
+            }
+        }
+    }
+
+    #[test]
+    fn apply_in_place_does_not_reallocate_when_shrinking() {
+        let mut text = String::from("first\r\nsecond\r\nthird\r\n");
+        let capacity = text.capacity();
+        let ptr = text.as_ptr();
+
+        LineEnding::LF.apply_in_place(&mut text);
+
+        assert_eq!(text, "first\nsecond\nthird\n");
+        assert_eq!(text.capacity(), capacity);
+        assert_eq!(text.as_ptr(), ptr);
+    }
+
+    #[test]
+    fn apply_in_place_bytes_handles_invalid_utf8() {
+        let mut bytes = vec![0xff, b'\n', 0xfe, b'\r', 0xfd];
+        LineEnding::CRLF.apply_in_place_bytes(&mut bytes);
+        assert_eq!(bytes, vec![0xff, b'\r', b'\n', 0xfe, b'\r', b'\n', 0xfd]);
+    }
 }
@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{ColumnUnit, LineCol, LineEnding, LineIndex};
+
+    #[test]
+    fn indexes_mixed_line_endings() {
+        let text = "line1\r\nline2\nline3\rline4";
+        let index = LineIndex::new(text);
+
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(index.line(0), Some("line1"));
+        assert_eq!(index.line(1), Some("line2"));
+        assert_eq!(index.line(2), Some("line3"));
+        assert_eq!(index.line(3), Some("line4"));
+        assert_eq!(index.line(4), None);
+
+        assert_eq!(index.line_ending(0), Some(LineEnding::CRLF));
+        assert_eq!(index.line_ending(1), Some(LineEnding::LF));
+        assert_eq!(index.line_ending(2), Some(LineEnding::CR));
+        assert_eq!(index.line_ending(3), None);
+
+        assert_eq!(index.line_range(0), Some(0..5));
+        assert_eq!(index.line_range_with_ending(0), Some(0..7));
+        assert_eq!(index.line_start(3), Some(19));
+    }
+
+    #[test]
+    fn handles_empty_and_trailing_lines() {
+        let index = LineIndex::new("");
+        assert_eq!(index.line_count(), 1);
+        assert_eq!(index.line(0), Some(""));
+
+        let index = LineIndex::new("a\n");
+        assert_eq!(index.line_count(), 2);
+        assert_eq!(index.line(1), Some(""));
+
+        let index = LineIndex::new("\r\r\n\n");
+        assert_eq!(index.line_count(), 4);
+        assert_eq!(
+            (0..3).map(|i| index.line_ending(i)).collect::<Vec<_>>(),
+            vec![
+                Some(LineEnding::CR),
+                Some(LineEnding::CRLF),
+                Some(LineEnding::LF)
+            ]
+        );
+    }
+
+    #[test]
+    fn finds_line_of_offset() {
+        let text = "ab\r\ncd\ne";
+        let index = LineIndex::new(text);
+
+        assert_eq!(index.line_of(0), Some(0));
+        assert_eq!(index.line_of(2), Some(0)); // `\r`
+        assert_eq!(index.line_of(3), Some(0)); // `\n` of CRLF
+        assert_eq!(index.line_of(4), Some(1));
+        assert_eq!(index.line_of(7), Some(2));
+        assert_eq!(index.line_of(8), Some(2)); // End of text
+        assert_eq!(index.line_of(9), None);
+    }
+
+    #[test]
+    fn reports_columns_in_each_unit() {
+        // `é` is 2 UTF-8 bytes, 1 char; `😀` is 4 UTF-8 bytes, 1 char, 2 UTF-16 units
+        let text = "x\r\né😀z";
+        let index = LineIndex::new(text);
+        let z = text.find('z').unwrap();
+
+        assert_eq!(
+            index.line_col(z, ColumnUnit::Byte),
+            Some(LineCol { line: 1, column: 6 })
+        );
+        assert_eq!(
+            index.line_col(z, ColumnUnit::Char),
+            Some(LineCol { line: 1, column: 2 })
+        );
+        assert_eq!(
+            index.line_col(z, ColumnUnit::Utf16),
+            Some(LineCol { line: 1, column: 3 })
+        );
+
+        // Not on a char boundary
+        assert_eq!(index.line_col(z - 1, ColumnUnit::Char), None);
+        assert!(index.line_col(z - 1, ColumnUnit::Byte).is_some());
+    }
+
+    #[test]
+    fn converts_line_col_back_to_offset() {
+        let text = "x\r\né😀z\rlast";
+        let index = LineIndex::new(text);
+
+        for unit in [ColumnUnit::Byte, ColumnUnit::Char, ColumnUnit::Utf16] {
+            for (offset, _) in text.char_indices().chain([(text.len(), ' ')]) {
+                let position = index.line_col(offset, unit).unwrap();
+                let in_line_ending = index
+                    .line_range(position.line)
+                    .is_some_and(|range| offset > range.end);
+
+                if !in_line_ending {
+                    assert_eq!(index.offset(position, unit), Some(offset), "{:?}", unit);
+                }
+            }
+        }
+
+        // Past the end of the line's content
+        assert_eq!(
+            index.offset(LineCol { line: 0, column: 2 }, ColumnUnit::Byte),
+            None
+        );
+        // Inside a surrogate pair
+        assert_eq!(
+            index.offset(LineCol { line: 1, column: 2 }, ColumnUnit::Utf16),
+            None
+        );
+        // Non-existent line
+        assert_eq!(
+            index.offset(LineCol { line: 9, column: 0 }, ColumnUnit::Byte),
+            None
+        );
+    }
+
+    #[test]
+    fn extracts_line_ranges() {
+        let index = LineIndex::new("zero\r\none\ntwo\rthree\n");
+
+        assert_eq!(index.lines_in(0..0), Some(vec![]));
+        assert_eq!(index.lines_in(1..4), Some(vec!["one", "two", "three"]));
+        assert_eq!(index.lines_in(4..5), Some(vec![""]));
+        assert_eq!(index.lines_in(4..6), None);
+        assert_eq!(
+            index.lines_with_endings_in(0..4),
+            Some(vec!["zero\r\n", "one\n", "two\r", "three\n"])
+        );
+    }
+}
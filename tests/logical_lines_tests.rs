@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{LineEnding, StrLineEndingExt};
+    use std::borrow::Cow;
+
+    #[test]
+    fn joins_continuations_across_all_line_ending_types() {
+        let text = "a \\\nb \\\r\nc \\\rd\re\n";
+        let lines: Vec<_> = text.logical_lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].joined(), "a b c d");
+        assert_eq!(lines[0].lines(), 0..4);
+        assert_eq!(lines[0].line_ending(), Some(LineEnding::CR));
+        assert_eq!(lines[1].joined(), "e");
+        assert_eq!(lines[1].lines(), 4..5);
+        assert_eq!(lines[1].line_ending(), Some(LineEnding::LF));
+    }
+
+    #[test]
+    fn round_trips_original_text() {
+        let text = "x = 1 \\\r\n  + 2\r\n\\\n\ny\\z\\\r";
+        let mut rebuilt = String::new();
+
+        for line in text.logical_lines() {
+            assert_eq!(&text[line.range()], line.raw());
+            rebuilt.push_str(line.raw());
+            if let Some(line_ending) = line.line_ending() {
+                rebuilt.push_str(line_ending.as_str());
+            }
+        }
+
+        assert_eq!(rebuilt, text);
+    }
+
+    #[test]
+    fn borrows_lines_without_continuations() {
+        let text = "plain\nback\\slash";
+        let lines: Vec<_> = text.logical_lines().collect();
+
+        assert!(matches!(lines[0].joined(), Cow::Borrowed("plain")));
+        assert!(matches!(lines[1].joined(), Cow::Borrowed("back\\slash")));
+        assert_eq!(lines[1].line_ending(), None);
+    }
+
+    #[test]
+    fn handles_continuation_at_end_of_text_and_empty_lines() {
+        let text = "\n\\\nlast \\\n";
+        let lines: Vec<_> = text.logical_lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].raw(), "");
+        assert_eq!(lines[0].lines(), 0..1);
+        assert_eq!(lines[1].joined(), "last ");
+        assert_eq!(lines[1].lines(), 1..3);
+        assert_eq!(lines[1].line_ending(), None);
+
+        assert_eq!("".logical_lines().count(), 0);
+    }
+
+    #[test]
+    fn keeps_trailing_continuation_in_raw() {
+        let text = "a\\\n";
+        let line = text.logical_lines().next().unwrap();
+
+        assert_eq!(line.raw(), "a\\\n");
+        assert_eq!(line.range(), 0..text.len());
+        assert_eq!(line.joined(), "a");
+        assert_eq!(line.line_ending(), None);
+        assert_eq!(line.lines(), 0..1);
+        assert_eq!(text.logical_lines().count(), 1);
+    }
+
+    #[test]
+    fn matches_physical_lines_without_continuations() {
+        let text = "one\r\ntwo\rthree\nfour";
+        let logical: Vec<_> = text.logical_lines().map(|line| line.raw()).collect();
+        let physical: Vec<_> = text.lines_any_ending().collect();
+
+        assert_eq!(logical, physical);
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{LineEnding, LoneCr};
+
+    #[test]
+    fn terminator_policy_matches_existing_behavior() {
+        let text = "a\rb\r\nc\nd\r";
+
+        assert_eq!(
+            LineEnding::normalize_with_lone_cr(text, LoneCr::Terminator),
+            LineEnding::normalize(text)
+        );
+        for line_ending in LineEnding::ALL {
+            assert_eq!(
+                line_ending.apply_with_lone_cr(text, LoneCr::Terminator),
+                line_ending.apply(text)
+            );
+        }
+        assert_eq!(
+            LineEnding::split_with_lone_cr(text, LoneCr::Terminator),
+            vec!["a", "b", "c", "d", ""]
+        );
+    }
+
+    #[test]
+    fn content_policy_keeps_lone_cr() {
+        let text = "a\rb\r\nc\nd\r";
+
+        assert_eq!(
+            LineEnding::normalize_with_lone_cr(text, LoneCr::Content),
+            "a\rb\nc\nd\r"
+        );
+        assert_eq!(
+            LineEnding::CRLF.apply_with_lone_cr(text, LoneCr::Content),
+            "a\rb\r\nc\r\nd\r"
+        );
+        assert_eq!(
+            LineEnding::split_with_lone_cr(text, LoneCr::Content),
+            vec!["a\rb", "c", "d\r"]
+        );
+    }
+
+    #[test]
+    fn overwrite_policy_keeps_final_rewrite() {
+        let progress: String = (0..=100)
+            .map(|percent| format!("\r{:>3}%", percent))
+            .collect::<String>()
+            + "\r\nfinished\n";
+
+        assert_eq!(
+            LineEnding::normalize_with_lone_cr(&progress, LoneCr::Overwrite),
+            "100%\nfinished\n"
+        );
+        assert_eq!(
+            LineEnding::CRLF.apply_with_lone_cr(&progress, LoneCr::Overwrite),
+            "100%\r\nfinished\r\n"
+        );
+    }
+
+    #[test]
+    fn overwrite_policy_keeps_only_the_last_rewrite() {
+        assert_eq!(
+            LineEnding::split_with_lone_cr("loading...\rok\nnext", LoneCr::Overwrite),
+            vec!["ok", "next"]
+        );
+        assert_eq!(
+            LineEnding::split_with_lone_cr("héllo\rwó\r", LoneCr::Overwrite),
+            vec!["wó"]
+        );
+        assert_eq!(
+            LineEnding::normalize_with_lone_cr("\r\r\n\r", LoneCr::Overwrite),
+            "\n"
+        );
+    }
+
+    #[test]
+    fn terminal_policy_emulates_terminal_columns() {
+        assert_eq!(
+            LineEnding::split_with_lone_cr("loading...\rok\nnext", LoneCr::Terminal),
+            vec!["okading...", "next"]
+        );
+        assert_eq!(
+            LineEnding::split_with_lone_cr("héllo\rwó\r", LoneCr::Terminal),
+            vec!["wóllo"]
+        );
+        assert_eq!(
+            LineEnding::CRLF.apply_with_lone_cr("10%\r100%\n", LoneCr::Terminal),
+            "100%\r\n"
+        );
+    }
+
+    #[test]
+    fn handles_empty_input() {
+        for policy in [
+            LoneCr::Terminator,
+            LoneCr::Content,
+            LoneCr::Overwrite,
+            LoneCr::Terminal,
+        ] {
+            assert_eq!(LineEnding::normalize_with_lone_cr("", policy), "");
+            assert_eq!(LineEnding::split_with_lone_cr("", policy), vec![""]);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{LineEnding, MalformedLineEnding};
+
+    #[test]
+    fn reports_malformed_sequences_as_single_terminators() {
+        let text = "a\n\rb\n\rc\r\r\nd\r\ne\rf\ng";
+        let report = LineEnding::report(text);
+
+        assert_eq!(report.malformed[&MalformedLineEnding::LFCR], 2);
+        assert_eq!(report.malformed[&MalformedLineEnding::CRCRLF], 1);
+        assert_eq!(report.well_formed[&LineEnding::CRLF], 1);
+        assert_eq!(report.well_formed[&LineEnding::CR], 1);
+        assert_eq!(report.well_formed[&LineEnding::LF], 1);
+        assert!(report.has_malformed());
+    }
+
+    #[test]
+    fn does_not_report_well_formed_sequences_as_malformed() {
+        let report = LineEnding::report("a\r\n\r\nb\r\n\r\n\r\nc\n\nd\r\re");
+
+        assert!(!report.has_malformed());
+        assert_eq!(report.well_formed[&LineEnding::CRLF], 5);
+        assert_eq!(report.well_formed[&LineEnding::LF], 2);
+        assert_eq!(report.well_formed[&LineEnding::CR], 2);
+
+        assert!(!LineEnding::report("").has_malformed());
+    }
+
+    #[test]
+    fn prefers_lf_and_crlf_over_lfcr() {
+        let report = LineEnding::report("a\n\r\nb");
+
+        assert!(!report.has_malformed());
+        assert_eq!(report.well_formed[&LineEnding::LF], 1);
+        assert_eq!(report.well_formed[&LineEnding::CRLF], 1);
+
+        // Other CRLFs make `\n\r\n` an LF followed by a CRLF, even with LFCRs
+        let report = LineEnding::report("a\n\rb\n\r\nc\r\nd");
+        assert_eq!(report.malformed[&MalformedLineEnding::LFCR], 1);
+        assert_eq!(report.well_formed[&LineEnding::LF], 1);
+        assert_eq!(report.well_formed[&LineEnding::CRLF], 2);
+    }
+
+    #[test]
+    fn reads_blank_lines_in_lfcr_text() {
+        let report = LineEnding::report("a\n\r\n\rb");
+
+        assert_eq!(report.malformed[&MalformedLineEnding::LFCR], 2);
+        assert_eq!(report.well_formed.values().sum::<usize>(), 0);
+
+        // A final LF after an LFCR line is kept as an LF
+        let report = LineEnding::report("a\n\rb\n\r\n");
+        assert_eq!(report.malformed[&MalformedLineEnding::LFCR], 2);
+        assert_eq!(report.well_formed[&LineEnding::LF], 1);
+    }
+
+    #[test]
+    fn reads_cr_before_crlf_in_cr_text() {
+        let report = LineEnding::report("a\rb\r\r\nc");
+
+        assert!(!report.has_malformed());
+        assert_eq!(report.well_formed[&LineEnding::CR], 2);
+        assert_eq!(report.well_formed[&LineEnding::CRLF], 1);
+
+        // With other CRLFs, `\r\r\n` is a doubled CRLF
+        let report = LineEnding::report("a\rb\r\r\nc\r\nd");
+        assert_eq!(report.malformed[&MalformedLineEnding::CRCRLF], 1);
+        assert_eq!(report.well_formed[&LineEnding::CR], 1);
+        assert_eq!(report.well_formed[&LineEnding::CRLF], 1);
+    }
+
+    #[test]
+    fn repairs_malformed_sequences() {
+        let text = "a\n\rb\r\r\nc\r\nd\re\n";
+
+        assert_eq!(
+            LineEnding::LF.apply_repairing_malformed(text),
+            "a\nb\nc\nd\ne\n"
+        );
+        assert_eq!(
+            LineEnding::CRLF.apply_repairing_malformed(text),
+            "a\r\nb\r\nc\r\nd\r\ne\r\n"
+        );
+        assert_eq!(
+            LineEnding::CR.apply_repairing_malformed(text),
+            "a\rb\rc\rd\re\r"
+        );
+    }
+
+    #[test]
+    fn repairs_blank_lines_in_lfcr_text() {
+        let text = "a\n\r\n\rb\n\r";
+
+        assert_eq!(LineEnding::LF.apply(text), "a\n\n\nb\n\n");
+        assert_eq!(LineEnding::LF.apply_repairing_malformed(text), "a\n\nb\n");
+        assert_eq!(
+            LineEnding::CRLF.apply_repairing_malformed(text),
+            "a\r\n\r\nb\r\n"
+        );
+    }
+
+    #[test]
+    fn repair_matches_apply_without_malformed_sequences() {
+        let text = "a\r\nb\nc\rd\r\n\r\n";
+
+        for line_ending in LineEnding::ALL {
+            assert_eq!(
+                line_ending.apply_repairing_malformed(text),
+                line_ending.apply(text)
+            );
+        }
+    }
+}
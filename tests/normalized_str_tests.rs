@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{LineEnding, NormalizedStr};
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::{BTreeSet, HashSet};
+    use std::hash::{Hash, Hasher};
+
+    const SAMPLES: [&str; 10] = [
+        "",
+        "a",
+        "a\nb",
+        "a\r\nb",
+        "a\rb",
+        "a\n\nb",
+        "a\r\n\r\nb\r\n",
+        "a\n\rb",
+        "a\r\r\nb",
+        "a\\r\\nb",
+    ];
+
+    fn hash_of<T: Hash>(value: T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn eq_matches_normalized_comparison() {
+        for a in SAMPLES {
+            for b in SAMPLES {
+                assert_eq!(
+                    LineEnding::eq_ignore_line_endings(a, b),
+                    LineEnding::normalize(a) == LineEnding::normalize(b),
+                    "{:?} vs {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hash_matches_normalized_hash() {
+        for sample in SAMPLES {
+            let mut hasher = DefaultHasher::new();
+            LineEnding::hash_ignoring_line_endings(sample, &mut hasher);
+
+            assert_eq!(
+                hasher.finish(),
+                hash_of(NormalizedStr::new(LineEnding::normalize(sample))),
+                "{:?}",
+                sample
+            );
+        }
+
+        assert_eq!(
+            hash_of(NormalizedStr::new("a\r\nb\r")),
+            hash_of(NormalizedStr::new("a\nb\n"))
+        );
+    }
+
+    #[test]
+    fn ordering_matches_normalized_ordering() {
+        for a in SAMPLES {
+            for b in SAMPLES {
+                assert_eq!(
+                    NormalizedStr::new(a).cmp(&NormalizedStr::new(b)),
+                    LineEnding::normalize(a).cmp(&LineEnding::normalize(b)),
+                    "{:?} vs {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn works_as_collection_key() {
+        let set: HashSet<NormalizedStr<String>> = ["a\r\nb", "a\nb", "a\rb", "a\n\nb"]
+            .into_iter()
+            .map(|s| NormalizedStr::new(s.to_string()))
+            .collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&NormalizedStr::new("a\nb".to_string())));
+
+        let ordered: BTreeSet<NormalizedStr<&str>> = ["b\r\n", "a\r\n", "a\n"]
+            .into_iter()
+            .map(NormalizedStr::new)
+            .collect();
+        assert_eq!(ordered.len(), 2);
+    }
+
+    #[test]
+    fn compares_borrowed_and_owned() {
+        let owned = NormalizedStr::new(String::from("x\r\ny"));
+        let borrowed = NormalizedStr::new("x\ny");
+
+        assert!(owned == borrowed);
+        assert_eq!(owned.to_string(), "x\r\ny");
+        assert_eq!(owned.into_inner(), "x\r\ny");
+    }
+}
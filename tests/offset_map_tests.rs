@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::LineEnding;
+
+    #[test]
+    fn produces_same_text_as_apply() {
+        let inputs = ["", "abc", "a\r\nb\rc\nd", "\r\r\n\n\r", "ünï\r\ncödé\n"];
+
+        for input in inputs {
+            for line_ending in LineEnding::ALL {
+                let (converted, map) = line_ending.apply_with_offset_map(input);
+                assert_eq!(converted, line_ending.apply(input), "{:?}", input);
+                assert_eq!(map.original_len(), input.len());
+                assert_eq!(map.converted_len(), converted.len());
+            }
+        }
+    }
+
+    #[test]
+    fn maps_content_offsets_in_both_directions() {
+        let original = "one\r\ntwo\nthree\rfour";
+
+        for line_ending in LineEnding::ALL {
+            let (converted, map) = line_ending.apply_with_offset_map(original);
+
+            // Every non-line-ending byte round-trips and points at the same character
+            for (offset, ch) in original.char_indices() {
+                if ch == '\r' || ch == '\n' {
+                    continue;
+                }
+
+                let mapped = map.to_converted(offset).unwrap();
+                assert_eq!(converted[mapped..].chars().next(), Some(ch));
+                assert_eq!(map.to_original(mapped), Some(offset));
+            }
+
+            assert_eq!(map.to_converted(original.len()), Some(converted.len()));
+            assert_eq!(map.to_original(converted.len()), Some(original.len()));
+        }
+    }
+
+    #[test]
+    fn maps_offsets_inside_line_endings_to_line_ending_start() {
+        let (_, map) = LineEnding::LF.apply_with_offset_map("a\r\nb");
+        assert_eq!(map.to_converted(1), Some(1)); // `\r`
+        assert_eq!(map.to_converted(2), Some(1)); // `\n` of CRLF
+        assert_eq!(map.to_converted(3), Some(2)); // `b`
+
+        let (_, map) = LineEnding::CRLF.apply_with_offset_map("a\nb");
+        assert_eq!(map.to_original(1), Some(1)); // `\r`
+        assert_eq!(map.to_original(2), Some(1)); // `\n` of CRLF
+        assert_eq!(map.to_original(3), Some(2)); // `b`
+    }
+
+    #[test]
+    fn rejects_out_of_range_offsets() {
+        let (_, map) = LineEnding::CRLF.apply_with_offset_map("a\nb");
+        assert_eq!(map.to_converted(4), None);
+        assert_eq!(map.to_original(5), None);
+
+        let (_, map) = LineEnding::LF.apply_with_offset_map("");
+        assert_eq!(map.to_converted(0), Some(0));
+        assert_eq!(map.to_original(1), None);
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use line_ending::{LineEnding, PeekableLineEndingExt};
+    use line_ending::{LineEnding, PeekableCharIndicesLineEndingExt, PeekableLineEndingExt};
 
     #[test]
     fn test_consume_line_endings() {
@@ -116,4 +116,208 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_consume_line_endings_from_bytes() {
+        let mut it = b"a\r\nb\nc\rd".iter().copied().peekable();
+        let mut consumed = Vec::new();
+        let mut content = Vec::new();
+
+        while it.peek().is_some() {
+            if let Some(le) = it.consume_line_ending() {
+                consumed.push(le);
+            } else {
+                content.push(it.next().unwrap());
+            }
+        }
+
+        assert_eq!(
+            consumed,
+            vec![LineEnding::CRLF, LineEnding::LF, LineEnding::CR]
+        );
+        assert_eq!(content, b"abcd");
+    }
+
+    #[test]
+    fn test_consume_line_endings_from_utf16() {
+        let utf16: Vec<u16> = "😀\r\n\\n\r".encode_utf16().collect();
+        let mut it = utf16.into_iter().peekable();
+        let mut consumed = Vec::new();
+        let mut content = Vec::new();
+
+        while it.peek().is_some() {
+            if let Some(le) = it.consume_line_ending() {
+                consumed.push(le);
+            } else {
+                content.push(it.next().unwrap());
+            }
+        }
+
+        assert_eq!(consumed, vec![LineEnding::CRLF, LineEnding::CR]);
+        assert_eq!(String::from_utf16(&content).unwrap(), "😀\\n");
+    }
+
+    #[test]
+    fn test_consume_line_endings_from_io_bytes() {
+        use std::io::Read;
+
+        let mut it = "x\r\ny".as_bytes().bytes().peekable();
+
+        assert_eq!(it.consume_line_ending(), None);
+        assert_eq!(it.next().unwrap().unwrap(), b'x');
+        assert_eq!(it.consume_line_ending(), Some(LineEnding::CRLF));
+        assert_eq!(it.next().unwrap().unwrap(), b'y');
+        assert_eq!(it.consume_line_ending(), None);
+    }
+
+    #[test]
+    fn test_consume_line_endings_from_non_clone_iterator() {
+        let (sender, receiver) = std::sync::mpsc::channel();
+        for ch in "\r\r\n\n".chars() {
+            sender.send(ch).unwrap();
+        }
+        drop(sender);
+
+        let mut it = receiver.into_iter().peekable();
+        assert_eq!(it.consume_line_ending(), Some(LineEnding::CR));
+        assert_eq!(it.consume_line_ending(), Some(LineEnding::CRLF));
+        assert_eq!(it.consume_line_ending(), Some(LineEnding::LF));
+        assert_eq!(it.consume_line_ending(), None);
+    }
+
+    #[test]
+    fn test_take_line() {
+        let mut it = "first\r\nsecond\nthird\rlast".chars().peekable();
+        let mut lines = Vec::new();
+
+        while let Some((line, ending)) = it.take_line::<String>() {
+            lines.push((line, ending));
+        }
+
+        assert_eq!(
+            lines,
+            vec![
+                ("first".to_string(), Some(LineEnding::CRLF)),
+                ("second".to_string(), Some(LineEnding::LF)),
+                ("third".to_string(), Some(LineEnding::CR)),
+                ("last".to_string(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_take_line_keeps_escaped_sequences_and_empty_lines() {
+        let mut it = "a\\r\\nb\n\nc".chars().peekable();
+
+        assert_eq!(
+            it.take_line::<String>(),
+            Some(("a\\r\\nb".to_string(), Some(LineEnding::LF)))
+        );
+        assert_eq!(
+            it.take_line::<String>(),
+            Some((String::new(), Some(LineEnding::LF)))
+        );
+        assert_eq!(it.take_line::<String>(), Some(("c".to_string(), None)));
+        assert_eq!(it.take_line::<String>(), None);
+    }
+
+    #[test]
+    fn test_take_line_from_bytes() {
+        let mut it = b"one\r\ntwo".iter().copied().peekable();
+
+        let (line, ending): (Vec<u8>, _) = it.take_line().unwrap();
+        assert_eq!(line, b"one");
+        assert_eq!(ending, Some(LineEnding::CRLF));
+    }
+
+    #[test]
+    fn test_skip_line_endings() {
+        let mut it = "\r\n\r\r\n\n\\ntext".chars().peekable();
+
+        assert_eq!(it.skip_line_endings(), 4);
+        assert_eq!(it.peek(), Some(&'\\'));
+        assert_eq!(it.skip_line_endings(), 0);
+    }
+
+    #[test]
+    fn test_skip_to_next_line() {
+        let mut it = "skip \\n this\r\nkeep\nend".chars().peekable();
+
+        assert_eq!(it.skip_to_next_line(), Some(LineEnding::CRLF));
+        assert_eq!(it.peek(), Some(&'k'));
+        assert_eq!(it.skip_to_next_line(), Some(LineEnding::LF));
+        assert_eq!(it.skip_to_next_line(), None);
+        assert_eq!(it.peek(), None);
+    }
+
+    #[test]
+    fn test_consume_line_ending_with_range() {
+        let text = "a\r\nbé\ncd\r\\ne";
+        let mut it = text.char_indices().peekable();
+        let mut terminators = Vec::new();
+
+        while it.peek().is_some() {
+            if let Some((le, range)) = it.consume_line_ending_with_range() {
+                assert_eq!(&text[range.clone()], le.as_str());
+                terminators.push((le, range));
+            } else {
+                it.next();
+            }
+        }
+
+        assert_eq!(
+            terminators,
+            vec![
+                (LineEnding::CRLF, 1..3),
+                (LineEnding::LF, 6..7),
+                (LineEnding::CR, 9..10),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_char_indices_support_line_helpers() {
+        let mut it = "ab\r\ncd".char_indices().peekable();
+
+        assert_eq!(it.consume_line_ending(), None);
+        assert_eq!(it.skip_to_next_line(), Some(LineEnding::CRLF));
+        assert_eq!(it.next(), Some((4, 'c')));
+    }
+
+    #[test]
+    fn test_line_helpers_stop_at_io_errors() {
+        use std::io::{self, Read};
+
+        /// A reader that fails on every read.
+        struct FailingReader;
+
+        impl Read for FailingReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk on fire"))
+            }
+        }
+
+        // `io::Bytes` yields `Err` forever from a reader that keeps failing
+        let reader = io::BufReader::new("ab\ncd".as_bytes().chain(FailingReader));
+        let mut it = reader.bytes().peekable();
+
+        let (line, ending): (Vec<io::Result<u8>>, _) = it.take_line().unwrap();
+        assert_eq!(line.len(), 2);
+        assert!(line.iter().all(Result::is_ok));
+        assert_eq!(ending, Some(LineEnding::LF));
+
+        let (line, ending): (Vec<io::Result<u8>>, _) = it.take_line().unwrap();
+        assert_eq!(line.len(), 2);
+        assert_eq!(ending, None);
+
+        assert!(it.take_line::<Vec<_>>().is_none());
+        assert_eq!(it.skip_to_next_line(), None);
+        assert_eq!(it.skip_line_endings(), 0);
+        assert!(it.peek().unwrap().is_err());
+
+        let reader = io::BufReader::new("skip".as_bytes().chain(FailingReader));
+        let mut it = reader.bytes().peekable();
+        assert_eq!(it.skip_to_next_line(), None);
+        assert!(it.next().unwrap().is_err());
+    }
 }
@@ -0,0 +1,140 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{BytesLineEndingExt, ReverseLines, StrLineEndingExt};
+    use std::io::{self, Cursor};
+
+    const SAMPLES: [&str; 9] = [
+        "",
+        "single",
+        "\n",
+        "\r\n\r\n",
+        "a\r\nb\nc\rd",
+        "a\r\nb\nc\rd\r\n",
+        "a\r\rb\n\n",
+        "a\n\rb",
+        "trailing cr\r",
+    ];
+
+    #[test]
+    fn str_lines_reverse_matches_forward() {
+        for sample in SAMPLES {
+            let mut forward: Vec<&str> = sample.lines_any_ending().collect();
+            forward.reverse();
+            let backward: Vec<&str> = sample.lines_any_ending().rev().collect();
+
+            assert_eq!(backward, forward, "{:?}", sample);
+        }
+    }
+
+    #[test]
+    fn byte_lines_match_str_lines() {
+        for sample in SAMPLES {
+            let expected: Vec<&[u8]> = sample.lines_any_ending().map(str::as_bytes).collect();
+            let forward: Vec<&[u8]> = sample.as_bytes().lines_any_ending().collect();
+            let mut backward: Vec<&[u8]> = sample.as_bytes().lines_any_ending().rev().collect();
+            backward.reverse();
+
+            assert_eq!(forward, expected, "{:?}", sample);
+            assert_eq!(backward, expected, "{:?}", sample);
+        }
+    }
+
+    #[test]
+    fn iterates_from_both_ends() {
+        let mut lines = "one\r\ntwo\rthree\nfour".lines_any_ending();
+
+        assert_eq!(lines.next(), Some("one"));
+        assert_eq!(lines.next_back(), Some("four"));
+        assert_eq!(lines.next_back(), Some("three"));
+        assert_eq!(lines.next(), Some("two"));
+        assert_eq!(lines.next(), None);
+        assert_eq!(lines.next_back(), None);
+    }
+
+    #[test]
+    fn reverse_reader_matches_str_lines_for_every_block_size() {
+        for sample in SAMPLES {
+            let mut expected: Vec<&str> = sample.lines_any_ending().collect();
+            expected.reverse();
+
+            for block_size in 1..=8 {
+                let lines: Vec<String> =
+                    ReverseLines::with_block_size(Cursor::new(sample), block_size)
+                        .unwrap()
+                        .collect::<io::Result<_>>()
+                        .unwrap();
+
+                assert_eq!(lines, expected, "{:?} (block size {})", sample, block_size);
+            }
+        }
+    }
+
+    #[test]
+    fn reverse_reader_keeps_crlf_split_across_blocks() {
+        // With a block size of 3, the blocks are "ab\r" and "\ncd", so the
+        // CRLF's `\r` and `\n` are read separately
+        let lines: Vec<String> = ReverseLines::with_block_size(Cursor::new("ab\r\ncd"), 3)
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(lines, vec!["cd", "ab"]);
+    }
+
+    #[test]
+    fn reverse_reader_reports_invalid_utf8() {
+        let mut lines = ReverseLines::new(Cursor::new(vec![b'o', b'k', b'\n', 0xff])).unwrap();
+
+        assert_eq!(
+            lines.next().unwrap().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+        assert_eq!(lines.next().unwrap().unwrap(), "ok");
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn reverse_reader_is_linear_in_line_length() {
+        use std::io::{Read, Seek, SeekFrom};
+
+        /// Counts the bytes read from the inner reader.
+        struct CountingReader<R> {
+            inner: R,
+            read: usize,
+        }
+
+        impl<R: Read> Read for CountingReader<R> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let read = self.inner.read(buf)?;
+                self.read += read;
+                Ok(read)
+            }
+        }
+
+        impl<R: Seek> Seek for CountingReader<R> {
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                self.inner.seek(pos)
+            }
+        }
+
+        // A 1 MiB line read 16 bytes at a time takes 65,536 blocks; copying the
+        // line so far for each block would move tens of gigabytes
+        let long_line = "x".repeat(1 << 20);
+        let text = format!("first\r\n{}\nlast\n", long_line);
+        let mut reader = CountingReader {
+            inner: Cursor::new(text.as_bytes()),
+            read: 0,
+        };
+
+        let lines: Vec<String> = ReverseLines::with_block_size(&mut reader, 16)
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "last");
+        assert!(lines[1] == long_line);
+        assert_eq!(lines[2], "first");
+        assert_eq!(reader.read, text.len());
+    }
+}
@@ -0,0 +1,148 @@
+#![cfg(feature = "serde")]
+
+#[cfg(test)]
+mod tests {
+    use line_ending::{
+        ConversionStats, FinalLineEnding, LineEnding, LineEndingPolicy, LineEndingReport,
+        LineEndingScores, LoneCr,
+    };
+
+    #[test]
+    fn serializes_canonical_names() {
+        assert_eq!(serde_json::to_string(&LineEnding::LF).unwrap(), "\"lf\"");
+        assert_eq!(
+            serde_json::to_string(&LineEnding::CRLF).unwrap(),
+            "\"crlf\""
+        );
+        assert_eq!(serde_json::to_string(&LineEnding::CR).unwrap(), "\"cr\"");
+    }
+
+    #[test]
+    fn deserializes_canonical_and_escaped_forms() {
+        let cases = [
+            ("\"lf\"", LineEnding::LF),
+            ("\"LF\"", LineEnding::LF),
+            ("\"\\\\n\"", LineEnding::LF),
+            ("\"\\n\"", LineEnding::LF),
+            ("\"crlf\"", LineEnding::CRLF),
+            ("\"CrLf\"", LineEnding::CRLF),
+            ("\"\\\\r\\\\n\"", LineEnding::CRLF),
+            ("\"\\r\\n\"", LineEnding::CRLF),
+            ("\"cr\"", LineEnding::CR),
+            ("\"\\\\r\"", LineEnding::CR),
+            ("\"\\r\"", LineEnding::CR),
+        ];
+
+        for (json, expected) in cases {
+            assert_eq!(
+                serde_json::from_str::<LineEnding>(json).unwrap(),
+                expected,
+                "Failed to deserialize {}",
+                json
+            );
+        }
+    }
+
+    #[test]
+    fn deserializes_native_as_current_platform() {
+        assert_eq!(
+            serde_json::from_str::<LineEnding>("\"native\"").unwrap(),
+            LineEnding::from_current_platform()
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_values() {
+        assert!(serde_json::from_str::<LineEnding>("\"auto\"").is_err());
+        assert!(serde_json::from_str::<LineEnding>("\"unknown\"").is_err());
+        assert!(serde_json::from_str::<LineEnding>("1").is_err());
+    }
+
+    #[test]
+    fn round_trips_scores() {
+        let scores = LineEnding::score_mixed_types("line1\nline2\r\nline3\rline4\n");
+        let json = serde_json::to_string(&scores).unwrap();
+        let restored: LineEndingScores = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, scores);
+        assert_eq!(restored[&LineEnding::LF], 2);
+        assert_eq!(restored[&LineEnding::CRLF], 1);
+        assert_eq!(restored[&LineEnding::CR], 1);
+    }
+
+    #[test]
+    fn round_trips_conversion_stats() {
+        let (_, stats) = LineEnding::LF.apply_with_stats("a\r\nb\rc\n");
+        let json = serde_json::to_string(&stats).unwrap();
+        let restored: ConversionStats = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, stats);
+        assert!(json.contains("\"target\":\"lf\""));
+    }
+
+    #[test]
+    fn round_trips_final_line_ending_policy() {
+        assert_eq!(
+            serde_json::to_string(&FinalLineEnding::Single).unwrap(),
+            "\"single\""
+        );
+        assert_eq!(
+            serde_json::from_str::<FinalLineEnding>("\"collapse\"").unwrap(),
+            FinalLineEnding::Collapse
+        );
+    }
+
+    #[test]
+    fn round_trips_line_ending_report() {
+        let report = LineEnding::report("a\n\rb\r\r\nc\n");
+        let json = serde_json::to_string(&report).unwrap();
+        let restored: LineEndingReport = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, report);
+        assert!(json.contains("\"lfcr\":1"));
+        assert!(json.contains("\"crcrlf\":1"));
+    }
+
+    #[test]
+    fn round_trips_lone_cr_policy() {
+        assert_eq!(
+            serde_json::to_string(&LoneCr::Overwrite).unwrap(),
+            "\"overwrite\""
+        );
+        assert_eq!(
+            serde_json::from_str::<LoneCr>("\"content\"").unwrap(),
+            LoneCr::Content
+        );
+        assert_eq!(
+            serde_json::from_str::<LoneCr>("\"terminal\"").unwrap(),
+            LoneCr::Terminal
+        );
+    }
+
+    #[test]
+    fn round_trips_line_ending_policy() {
+        #[derive(serde::Deserialize)]
+        struct Config {
+            line_ending: LineEndingPolicy,
+        }
+
+        let config: Config = serde_json::from_str(r#"{"line_ending": "AUTO"}"#).unwrap();
+        assert_eq!(config.line_ending, LineEndingPolicy::Auto);
+
+        let config: Config = serde_json::from_str(r#"{"line_ending": "windows"}"#).unwrap();
+        assert_eq!(
+            config.line_ending,
+            LineEndingPolicy::Fixed(LineEnding::CRLF)
+        );
+
+        assert_eq!(
+            serde_json::to_string(&LineEndingPolicy::Auto).unwrap(),
+            "\"auto\""
+        );
+        assert_eq!(
+            serde_json::to_string(&LineEndingPolicy::Fixed(LineEnding::CR)).unwrap(),
+            "\"cr\""
+        );
+        assert!(serde_json::from_str::<LineEndingPolicy>("\"detect\"").is_err());
+    }
+}
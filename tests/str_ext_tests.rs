@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{LineEnding, LineIndex, StrLineEndingExt};
+
+    #[test]
+    fn detects_line_ending_on_str_and_string() {
+        assert_eq!("a\r\nb\r\nc".line_ending(), LineEnding::CRLF);
+        assert_eq!("a\rb\rc".line_ending(), LineEnding::CR);
+        assert_eq!(String::from("a\nb\nc").line_ending(), LineEnding::LF);
+    }
+
+    #[test]
+    fn converts_and_normalizes() {
+        let mixed = "first\r\nsecond\rthird\nfourth";
+
+        assert_eq!(
+            mixed.to_line_ending(LineEnding::CRLF),
+            LineEnding::CRLF.apply(mixed)
+        );
+        assert_eq!(
+            mixed.to_line_ending(LineEnding::CR),
+            LineEnding::CR.apply(mixed)
+        );
+        assert_eq!(mixed.normalize_line_endings(), LineEnding::normalize(mixed));
+    }
+
+    #[test]
+    fn lines_any_splits_mixed_line_endings() {
+        let lines: Vec<&str> = "line1\r\nline2\nline3\rline4".lines_any_ending().collect();
+        assert_eq!(lines, vec!["line1", "line2", "line3", "line4"]);
+    }
+
+    #[test]
+    fn lines_any_handles_empty_lines_and_trailing_terminators() {
+        let lines: Vec<&str> = "\r\n\n\rline\r".lines_any_ending().collect();
+        assert_eq!(lines, vec!["", "", "", "line"]);
+
+        assert_eq!("".lines_any_ending().count(), 0);
+        assert_eq!("\r\n".lines_any_ending().collect::<Vec<_>>(), vec![""]);
+
+        // A `\r` followed by `\n` is a single CRLF, but `\n\r` is two line endings
+        assert_eq!(
+            "a\n\rb".lines_any_ending().collect::<Vec<_>>(),
+            vec!["a", "", "b"]
+        );
+    }
+
+    #[test]
+    fn lines_any_ignores_escaped_line_endings() {
+        let lines: Vec<&str> = "First\\r\\nSecond\nThird".lines_any_ending().collect();
+        assert_eq!(lines, vec!["First\\r\\nSecond", "Third"]);
+    }
+
+    #[test]
+    fn detects_mixed_line_endings() {
+        assert!("a\r\nb\nc".has_mixed_line_endings());
+        assert!("a\rb\nc".has_mixed_line_endings());
+        assert!(!"a\nb\nc".has_mixed_line_endings());
+        assert!(!"a\r\nb\r\n".has_mixed_line_endings());
+        assert!(!"".has_mixed_line_endings());
+    }
+
+    #[test]
+    fn extracts_line_ranges_with_forward_scan() {
+        let text = "zero\r\none\ntwo\rthree\n";
+
+        assert_eq!(text.lines_in(0..2), Some(vec!["zero", "one"]));
+        assert_eq!(text.lines_in(2..5), Some(vec!["two", "three", ""]));
+        assert_eq!(text.lines_in(5..5), Some(vec![]));
+        assert_eq!(text.lines_in(2..10), None);
+        assert_eq!(text.lines_in(6..6), None);
+        assert_eq!(
+            text.lines_with_endings_in(1..4),
+            Some(vec!["one\n", "two\r", "three\n"])
+        );
+    }
+
+    #[test]
+    fn line_ranges_match_line_index() {
+        for text in [
+            "",
+            "a",
+            "a\n",
+            "a\r\n\rb",
+            "\n\n",
+            "zero\r\none\ntwo\rthree",
+        ] {
+            let index = LineIndex::new(text);
+
+            for start in 0..=index.line_count() + 1 {
+                for end in 0..=index.line_count() + 1 {
+                    assert_eq!(
+                        text.lines_in(start..end),
+                        index.lines_in(start..end),
+                        "{:?} {}..{}",
+                        text,
+                        start,
+                        end
+                    );
+                    assert_eq!(
+                        text.lines_with_endings_in(start..end),
+                        index.lines_with_endings_in(start..end)
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::LineEnding;
+
+    fn line_ending_count(s: &str) -> usize {
+        LineEnding::score_mixed_types(s).values().sum()
+    }
+
+    #[test]
+    fn preserves_each_line_ending() {
+        let text = "crlf \t\r\nlf  \ncr \rlast \u{a0}";
+
+        assert_eq!(
+            LineEnding::trim_trailing_whitespace(text),
+            "crlf\r\nlf\ncr\rlast"
+        );
+    }
+
+    #[test]
+    fn keeps_crlf_files_as_crlf() {
+        let text = "first   \r\nsecond\t\r\n\r\n   \r\n";
+        let trimmed = LineEnding::trim_trailing_whitespace(text);
+
+        assert_eq!(trimmed, "first\r\nsecond\r\n\r\n\r\n");
+        assert_eq!(LineEnding::from(trimmed.as_str()), LineEnding::CRLF);
+        assert_eq!(LineEnding::score_mixed_types(&trimmed)[&LineEnding::LF], 0);
+    }
+
+    #[test]
+    fn leaves_leading_whitespace_and_escapes_alone() {
+        let text = "  indented  \n\\r\\n  ";
+        assert_eq!(
+            LineEnding::trim_trailing_whitespace(text),
+            "  indented\n\\r\\n"
+        );
+    }
+
+    #[test]
+    fn trims_and_converts_in_one_pass() {
+        let text = "first  \r\nsecond\t\nthird \rfourth  \n";
+
+        for line_ending in LineEnding::ALL {
+            assert_eq!(
+                line_ending.apply_trimming_trailing_whitespace(text),
+                line_ending.apply(&LineEnding::trim_trailing_whitespace(text))
+            );
+        }
+    }
+
+    #[test]
+    fn does_not_join_lone_cr_and_lf_into_crlf() {
+        assert_eq!(LineEnding::trim_trailing_whitespace("a\r \nb"), "a\r \nb");
+        assert_eq!(LineEnding::trim_trailing_whitespace("\r \n"), "\r \n");
+        assert_eq!(
+            LineEnding::trim_trailing_whitespace("a \r\t \n \r\n"),
+            "a\r\t \n\r\n"
+        );
+
+        for text in ["a\r \nb", "\r \n"] {
+            let trimmed = LineEnding::trim_trailing_whitespace(text);
+            assert_eq!(line_ending_count(&trimmed), line_ending_count(text));
+        }
+    }
+
+    #[test]
+    fn trims_lone_cr_lines_when_converting() {
+        assert_eq!(
+            LineEnding::LF.apply_trimming_trailing_whitespace("a\r \nb"),
+            "a\n\nb"
+        );
+        assert_eq!(
+            LineEnding::CRLF.apply_trimming_trailing_whitespace("\r \n"),
+            "\r\n\r\n"
+        );
+        assert_eq!(
+            LineEnding::CR.apply_trimming_trailing_whitespace("a\r \nb"),
+            "a\r\rb"
+        );
+    }
+
+    #[test]
+    fn one_pass_matches_converting_then_trimming_for_generated_text() {
+        // A small xorshift generator keeps the cases deterministic
+        let mut state: u32 = 0x9e37_79b9;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state
+        };
+
+        for _ in 0..2000 {
+            let len = next() % 12;
+            let text: String = (0..len)
+                .map(|_| ['a', ' ', '\t', '\r', '\n'][(next() % 5) as usize])
+                .collect();
+            let trimmed = LineEnding::trim_trailing_whitespace(&text);
+
+            assert_eq!(
+                line_ending_count(&trimmed),
+                line_ending_count(&text),
+                "{:?}",
+                text
+            );
+            for line_ending in LineEnding::ALL {
+                let converted = line_ending.apply_trimming_trailing_whitespace(&text);
+
+                assert_eq!(
+                    converted,
+                    LineEnding::trim_trailing_whitespace(&line_ending.apply(&text)),
+                    "{:?}",
+                    text
+                );
+                assert_eq!(line_ending_count(&converted), line_ending_count(&text));
+                assert!(
+                    converted
+                        .split(line_ending.as_str())
+                        .all(|line| line.trim_end() == line),
+                    "{:?}",
+                    text
+                );
+            }
+        }
+    }
+}
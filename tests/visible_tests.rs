@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use line_ending::{LineEnding, Markers, VisibleLineEndings};
+
+    const TEXT: &str = "first\r\nsecond\nthird\rfourth";
+
+    #[test]
+    fn renders_each_marker_style() {
+        assert_eq!(
+            VisibleLineEndings::new(TEXT).to_string(),
+            "first␍␊\nsecond␊\nthird␍\nfourth"
+        );
+        assert_eq!(
+            VisibleLineEndings::new(TEXT)
+                .markers(Markers::ESCAPED)
+                .to_string(),
+            "first\\r\\n\nsecond\\n\nthird\\r\nfourth"
+        );
+        assert_eq!(
+            VisibleLineEndings::new(TEXT)
+                .markers(Markers::CARET)
+                .to_string(),
+            "first^M$\nsecond$\nthird^M\nfourth"
+        );
+        assert_eq!(
+            VisibleLineEndings::new(TEXT)
+                .markers(Markers::RETURN_SYMBOL)
+                .to_string(),
+            "first⏎\nsecond⏎\nthird⏎\nfourth"
+        );
+    }
+
+    #[test]
+    fn supports_custom_markers_and_annotations() {
+        let markers = Markers {
+            crlf: "<CRLF>",
+            lf: "<LF>",
+            cr: "<CR>",
+        };
+
+        assert_eq!(
+            VisibleLineEndings::new("a\r\nb\n")
+                .markers(markers)
+                .annotate(true)
+                .to_string(),
+            "a<CRLF>  [CRLF]\nb<LF>  [LF]\n"
+        );
+        assert_eq!(markers.for_line_ending(LineEnding::CR), "<CR>");
+    }
+
+    #[test]
+    fn renders_text_without_line_endings_unchanged() {
+        assert_eq!(VisibleLineEndings::new("").to_string(), "");
+        assert_eq!(VisibleLineEndings::new("a\\r\\nb").to_string(), "a\\r\\nb");
+    }
+
+    #[test]
+    fn output_has_one_lf_line_per_input_line() {
+        let rendered = VisibleLineEndings::new(TEXT).to_string();
+        assert_eq!(rendered.lines().count(), 4);
+        assert_eq!(LineEnding::score_mixed_types(&rendered)[&LineEnding::CR], 0);
+    }
+}